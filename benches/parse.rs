@@ -45,7 +45,7 @@ fn get_model() -> MutexGuard<'static, udpipe_rs::Model> {
 /// Parse text and collect all sentences.
 fn parse_all(text: &str) -> Vec<udpipe_rs::Sentence> {
     get_model()
-        .parser(text)
+        .parser(text, udpipe_rs::ParseOptions::default())
         .expect("Failed to create parser")
         .collect::<Result<Vec<_>, _>>()
         .expect("Failed to parse")
@@ -2,6 +2,7 @@ use std::env;
 use std::fs::{self, File};
 use std::io::{Cursor, Read};
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 const UDPIPE_VERSION: &str = "v1.4.0";
 const UDPIPE_URL: &str = "https://github.com/ufal/udpipe/archive/refs/tags/v1.4.0.zip";
@@ -20,36 +21,51 @@ fn main() {
 
     // Collect all UDPipe C++ source files
     let sources = collect_sources(&src_dir);
+    let wrapper_cpp = manifest_dir.join("src/udpipe_wrapper.cpp");
+    let wrapper_h = manifest_dir.join("src/udpipe_wrapper.h");
 
-    // Build UDPipe as a static library
-    let mut build = cc::Build::new();
-    build
-        .cpp(true)
-        .opt_level(2)
-        .flag_if_supported("-std=c++11")
-        .flag_if_supported("-w") // Suppress warnings from UDPipe
-        .include(&src_dir)
-        .include(src_dir.join("model"))
-        .include(src_dir.join("morphodita"))
-        .include(src_dir.join("parsito"))
-        .include(src_dir.join("sentence"))
-        .include(src_dir.join("unilib"))
-        .include(src_dir.join("utils"))
-        .include(src_dir.join("tokenizer"))
-        .include(src_dir.join("trainer"))
-        .define("NDEBUG", None);
-
-    for source in &sources {
-        build.file(source);
-    }
+    let target = env::var("TARGET").unwrap();
+    let lib_path = compiled_lib_path(&out_dir, &target);
 
-    // Also compile our C wrapper
-    build.file(manifest_dir.join("src/udpipe_wrapper.cpp"));
+    // Skip the (expensive) C++ recompile entirely when the static lib is
+    // already newer than every source and wrapper file that feeds it.
+    let mut inputs = sources.clone();
+    inputs.push(wrapper_cpp.clone());
+    inputs.push(wrapper_h.clone());
 
-    build.compile("udpipe");
+    if is_lib_up_to_date(&lib_path, &inputs) {
+        println!("cargo:rustc-link-search=native={}", out_dir.display());
+        println!("cargo:rustc-link-lib=static=udpipe");
+    } else {
+        // Build UDPipe as a static library
+        let mut build = cc::Build::new();
+        build
+            .cpp(true)
+            .opt_level(2)
+            .flag_if_supported("-std=c++11")
+            .flag_if_supported("-w") // Suppress warnings from UDPipe
+            .include(&src_dir)
+            .include(src_dir.join("model"))
+            .include(src_dir.join("morphodita"))
+            .include(src_dir.join("parsito"))
+            .include(src_dir.join("sentence"))
+            .include(src_dir.join("unilib"))
+            .include(src_dir.join("utils"))
+            .include(src_dir.join("tokenizer"))
+            .include(src_dir.join("trainer"))
+            .define("NDEBUG", None);
+
+        for source in &sources {
+            build.file(source);
+        }
+
+        // Also compile our C wrapper
+        build.file(&wrapper_cpp);
+
+        build.compile("udpipe");
+    }
 
     // Link C++ standard library
-    let target = env::var("TARGET").unwrap();
     if target.contains("apple") {
         println!("cargo:rustc-link-lib=c++");
     } else if target.contains("linux") {
@@ -63,25 +79,81 @@ fn main() {
     // Tell cargo to rerun if wrapper sources change
     println!("cargo:rerun-if-changed=src/udpipe_wrapper.cpp");
     println!("cargo:rerun-if-changed=src/udpipe_wrapper.h");
+    println!("cargo:rerun-if-env-changed=UDPIPE_SOURCE_DIR");
+    println!("cargo:rerun-if-env-changed=UDPIPE_TARBALL");
+}
+
+/// Returns the path `cc::Build::compile("udpipe")` produces its static
+/// library at, without actually invoking the compiler.
+fn compiled_lib_path(out_dir: &Path, target: &str) -> PathBuf {
+    if target.contains("msvc") {
+        out_dir.join("udpipe.lib")
+    } else {
+        out_dir.join("libudpipe.a")
+    }
+}
+
+/// Returns true if `lib_path` exists and is newer than every path in
+/// `inputs`, meaning the already-compiled static lib can be reused as-is.
+fn is_lib_up_to_date(lib_path: &Path, inputs: &[PathBuf]) -> bool {
+    let Ok(lib_mtime) = fs::metadata(lib_path).and_then(|m| m.modified()) else {
+        return false;
+    };
+
+    inputs.iter().all(|input| mtime_before(input, lib_mtime))
+}
+
+fn mtime_before(path: &Path, bound: SystemTime) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .is_ok_and(|mtime| mtime < bound)
 }
 
+/// Obtains the UDPipe source tree, honoring (in order) `UDPIPE_SOURCE_DIR`
+/// (an already-extracted tree), `UDPIPE_TARBALL` (a local copy of the
+/// release zip), and finally falling back to downloading the zip from
+/// `UDPIPE_URL`. This keeps air-gapped CI, reproducible builds, and offline
+/// `cargo vendor` workflows working without network access.
 fn download_udpipe(out_dir: &Path, udpipe_dir: &Path) {
-    // Create output directory
     fs::create_dir_all(out_dir).expect("Failed to create output directory");
 
-    // Download the zip file using ureq
-    let response = ureq::get(UDPIPE_URL)
-        .call()
-        .expect("Failed to download UDPipe source");
+    if let Ok(source_dir) = env::var("UDPIPE_SOURCE_DIR") {
+        let source_dir = PathBuf::from(source_dir);
+        copy_dir_all(&source_dir, udpipe_dir).unwrap_or_else(|e| {
+            panic!(
+                "Failed to copy UDPIPE_SOURCE_DIR ({}): {}",
+                source_dir.display(),
+                e
+            )
+        });
+        patch_udpipe_source(udpipe_dir);
+        return;
+    }
 
-    let mut zip_data = Vec::new();
-    response
-        .into_body()
-        .into_reader()
-        .read_to_end(&mut zip_data)
-        .expect("Failed to read UDPipe zip data");
+    let zip_data = if let Ok(tarball_path) = env::var("UDPIPE_TARBALL") {
+        fs::read(&tarball_path)
+            .unwrap_or_else(|e| panic!("Failed to read UDPIPE_TARBALL ({tarball_path}): {e}"))
+    } else {
+        let response = ureq::get(UDPIPE_URL)
+            .call()
+            .expect("Failed to download UDPipe source");
 
-    // Extract the zip file using zip crate
+        let mut zip_data = Vec::new();
+        response
+            .into_body()
+            .into_reader()
+            .read_to_end(&mut zip_data)
+            .expect("Failed to read UDPipe zip data");
+        zip_data
+    };
+
+    extract_zip(out_dir, udpipe_dir, &zip_data);
+    patch_udpipe_source(udpipe_dir);
+}
+
+/// Extracts `zip_data` into `out_dir` and renames the resulting
+/// `udpipe-<version>` directory to `udpipe_dir`.
+fn extract_zip(out_dir: &Path, udpipe_dir: &Path, zip_data: &[u8]) {
     let cursor = Cursor::new(zip_data);
     let mut archive = zip::ZipArchive::new(cursor).expect("Failed to read zip archive");
 
@@ -103,7 +175,6 @@ fn download_udpipe(out_dir: &Path, udpipe_dir: &Path) {
         }
     }
 
-    // Rename extracted directory to udpipe_dir
     // GitHub extracts tags without the 'v' prefix
     let version_num = UDPIPE_VERSION.trim_start_matches('v');
     let extracted_dir = out_dir.join(format!("udpipe-{}", version_num));
@@ -113,9 +184,21 @@ fn download_udpipe(out_dir: &Path, udpipe_dir: &Path) {
         }
         fs::rename(&extracted_dir, udpipe_dir).expect("Failed to rename UDPipe directory");
     }
+}
 
-    // Patch empty if-body bug in v1.2.0-v1.4.0
-    patch_udpipe_source(udpipe_dir);
+/// Recursively copies a directory tree, used to pull in `UDPIPE_SOURCE_DIR`.
+fn copy_dir_all(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let dest = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &dest)?;
+        } else {
+            fs::copy(entry.path(), &dest)?;
+        }
+    }
+    Ok(())
 }
 
 fn patch_udpipe_source(udpipe_dir: &Path) {
@@ -4,11 +4,21 @@
 //!   cargo run --example parse_text
 //!   cargo run --example parse_text -- "Your custom text here."
 //!   cargo run --example parse_text -- "Text" path/to/model.udpipe
+//!   cargo run --example parse_text -- "Text" path/to/model.udpipe --conllu
 
 use std::env;
 
+use udpipe_rs::{ParseOptions, Sentence};
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    let conllu = if let Some(pos) = args.iter().position(|a| a == "--conllu") {
+        args.remove(pos);
+        true
+    } else {
+        false
+    };
 
     let text = args
         .get(1)
@@ -35,7 +45,16 @@ fn main() {
     println!("Parsing: {}", text);
     println!();
 
-    let words = model.parse(text).expect("Failed to parse");
+    let words = model
+        .parse(text, ParseOptions::default())
+        .expect("Failed to parse");
+
+    if conllu {
+        for sentence in Sentence::group(words) {
+            print!("{}", sentence.to_conllu());
+        }
+        return;
+    }
 
     // Print header
     println!(
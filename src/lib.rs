@@ -6,7 +6,7 @@
 //! # Example
 //!
 //! ```no_run
-//! use udpipe_rs::Model;
+//! use udpipe_rs::{Model, ParseOptions};
 //!
 //! // Download a model by language (one-time setup)
 //! let model_path = udpipe_rs::download_model("english-ewt", ".")
@@ -14,7 +14,7 @@
 //!
 //! // Load and use the model
 //! let model = Model::load(&model_path).expect("Failed to load model");
-//! let words = model.parse("Hello world!").expect("Failed to parse");
+//! let words = model.parse("Hello world!", ParseOptions::default()).expect("Failed to parse");
 //!
 //! for word in words {
 //!     println!("{}: {} ({})", word.form, word.upostag, word.deprel);
@@ -25,7 +25,7 @@
 
 use std::ffi::{CStr, CString};
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Base URL for the LINDAT/CLARIAH-CZ model repository (UD 2.5).
 const MODEL_BASE_URL: &str =
@@ -78,6 +78,11 @@ pub struct Word {
     pub feats: String,
     /// Dependency relation to head (root, nsubj, obj, etc.).
     pub deprel: String,
+    /// Enhanced dependency graph edges (the `DEPS` column), e.g.
+    /// `"2:obj|4:conj"`. Only ever populated by [`Sentence::from_conllu`];
+    /// the underlying tokenize/tag/parse pipeline doesn't produce enhanced
+    /// dependencies, so parsed text always leaves this empty.
+    pub deps: String,
     /// Miscellaneous annotations (e.g., "SpaceAfter=No").
     pub misc: String,
     /// 1-based index of this word within its sentence.
@@ -101,6 +106,7 @@ impl Word {
     /// #     xpostag: String::new(),
     /// #     feats: "Mood=Imp|VerbForm=Fin".to_string(),
     /// #     deprel: "root".to_string(),
+    /// #     deps: String::new(),
     /// #     misc: String::new(),
     /// #     id: 1,
     /// #     head: 0,
@@ -125,6 +131,7 @@ impl Word {
     /// #     xpostag: String::new(),
     /// #     feats: "Mood=Imp|VerbForm=Fin".to_string(),
     /// #     deprel: "root".to_string(),
+    /// #     deps: String::new(),
     /// #     misc: String::new(),
     /// #     id: 1,
     /// #     head: 0,
@@ -177,6 +184,472 @@ impl Word {
     pub fn space_after(&self) -> bool {
         !self.misc.contains("SpaceAfter=No")
     }
+
+    /// Renders this word as a single CoNLL-U token row.
+    ///
+    /// Produces the ten tab-separated columns (ID, FORM, LEMMA, UPOS, XPOS,
+    /// FEATS, HEAD, DEPREL, DEPS, MISC). Other empty fields use the
+    /// missing-value sentinel, `_`. `FEATS` pairs are sorted by key, per the
+    /// CoNLL-U spec, regardless of the order they're stored in.
+    ///
+    /// See [`Sentence::from_conllu`] for the multiword-token and
+    /// empty/enhanced-node rows this doesn't produce on its own; those round
+    /// -trip through [`Sentence::to_conllu`] instead, since `Word` can only
+    /// ever represent a single numbered token.
+    ///
+    /// # Example
+    /// ```
+    /// # use udpipe_rs::Word;
+    /// # let word = Word {
+    /// #     form: "dog".to_string(),
+    /// #     lemma: "dog".to_string(),
+    /// #     upostag: "NOUN".to_string(),
+    /// #     xpostag: String::new(),
+    /// #     feats: "Number=Sing".to_string(),
+    /// #     deprel: "nsubj".to_string(),
+    /// #     deps: String::new(),
+    /// #     misc: "SpaceAfter=No".to_string(),
+    /// #     id: 2,
+    /// #     head: 3,
+    /// #     sentence_id: 0,
+    /// # };
+    /// assert_eq!(
+    ///     word.to_conllu(),
+    ///     "2\tdog\tdog\tNOUN\t_\tNumber=Sing\t3\tnsubj\t_\tSpaceAfter=No"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_conllu(&self) -> String {
+        let field = |s: &str| {
+            if s.is_empty() {
+                "_".to_string()
+            } else {
+                s.to_string()
+            }
+        };
+
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.id,
+            field(&self.form),
+            field(&self.lemma),
+            field(&self.upostag),
+            field(&self.xpostag),
+            sort_feats(&self.feats),
+            self.head,
+            field(&self.deprel),
+            field(&self.deps),
+            field(&self.misc),
+        )
+    }
+}
+
+/// Sorts a pipe-separated `Key=Value` feature string by key, as the CoNLL-U
+/// spec requires for the `FEATS` column. Returns `_` for an empty string.
+fn sort_feats(feats: &str) -> String {
+    if feats.is_empty() {
+        return "_".to_string();
+    }
+
+    let mut pairs: Vec<&str> = feats.split('|').collect();
+    pairs.sort_unstable();
+    pairs.join("|")
+}
+
+/// A raw token produced by sentence segmentation and tokenization, before
+/// tagging or parsing has run.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Token {
+    /// The surface form (actual text).
+    pub form: String,
+    /// Miscellaneous annotations (e.g., "SpaceAfter=No").
+    pub misc: String,
+    /// 1-based index of this token within its sentence.
+    pub id: i32,
+    /// 0-based index of the sentence this token belongs to.
+    pub sentence_id: i32,
+}
+
+impl Token {
+    /// Returns true if there's a space after this token.
+    ///
+    /// See [`Word::space_after`] for the `SpaceAfter=No` convention this follows.
+    #[must_use]
+    pub fn space_after(&self) -> bool {
+        !self.misc.contains("SpaceAfter=No")
+    }
+
+    /// Converts this token into a [`Word`] with empty tagging and dependency
+    /// fields, ready to be filled in by [`Model::tag`] and
+    /// [`Model::parse_dependencies`].
+    #[must_use]
+    pub fn into_word(self) -> Word {
+        Word {
+            form: self.form,
+            lemma: String::new(),
+            upostag: String::new(),
+            xpostag: String::new(),
+            feats: String::new(),
+            deprel: String::new(),
+            deps: String::new(),
+            misc: self.misc,
+            id: self.id,
+            head: 0,
+            sentence_id: self.sentence_id,
+        }
+    }
+}
+
+impl From<Word> for Token {
+    fn from(word: Word) -> Self {
+        Token {
+            form: word.form,
+            misc: word.misc,
+            id: word.id,
+            sentence_id: word.sentence_id,
+        }
+    }
+}
+
+/// A single sentence: its annotated words plus CoNLL-U sentence-level text.
+///
+/// This is the document-level counterpart to the flat `Vec<Word>` returned by
+/// [`Model::parse`], grouping words back into sentences for round-tripping
+/// through the CoNLL-U format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sentence {
+    /// The words in this sentence, in surface order.
+    pub words: Vec<Word>,
+    /// Multiword-token (`1-2`) and empty/enhanced-node (`5.1`) rows that
+    /// `Word` can't represent, preserved verbatim (the full tab-separated
+    /// row, ID column included) so [`Sentence::to_conllu`] can re-emit them
+    /// in their original position. Each entry pairs a row with the number of
+    /// `words` that precede it in the block, so `0` sorts before every word
+    /// and `words.len()` sorts after all of them.
+    ///
+    /// Always empty for sentences built any other way than
+    /// [`Sentence::from_conllu`].
+    pub raw_rows: Vec<(usize, String)>,
+}
+
+impl Sentence {
+    /// Groups a flat, sentence-ordered word list (as returned by
+    /// [`Model::parse`]) back into per-sentence chunks.
+    ///
+    /// # Example
+    /// ```
+    /// # use udpipe_rs::{Sentence, Word};
+    /// # let word = |sentence_id| Word {
+    /// #     form: "Hi".to_string(), lemma: "hi".to_string(), upostag: "INTJ".to_string(),
+    /// #     xpostag: String::new(), feats: String::new(), deprel: "root".to_string(),
+    /// #     deps: String::new(), misc: String::new(), id: 1, head: 0, sentence_id,
+    /// # };
+    /// let sentences = Sentence::group(vec![word(0), word(1)]);
+    /// assert_eq!(sentences.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn group(words: Vec<Word>) -> Vec<Sentence> {
+        let mut sentences: Vec<Sentence> = Vec::new();
+        for word in words {
+            match sentences.last_mut() {
+                Some(sentence)
+                    if sentence.words.last().unwrap().sentence_id == word.sentence_id =>
+                {
+                    sentence.words.push(word);
+                }
+                _ => sentences.push(Sentence {
+                    words: vec![word],
+                    raw_rows: Vec::new(),
+                }),
+            }
+        }
+        sentences
+    }
+
+    /// Reconstructs the original sentence text from the word forms, honoring
+    /// each word's [`Word::space_after`] annotation.
+    #[must_use]
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for word in &self.words {
+            text.push_str(&word.form);
+            if word.space_after() {
+                text.push(' ');
+            }
+        }
+        text.trim_end().to_string()
+    }
+
+    /// Renders this sentence as a CoNLL-U block: `# sent_id` and `# text`
+    /// comment lines, one row per word (interleaved with any
+    /// [`Sentence::raw_rows`] at their original positions), and the trailing
+    /// blank line that separates sentences in the format.
+    ///
+    /// `# sent_id`/`# text` are always re-derived rather than preserved
+    /// verbatim (this crate doesn't keep the original comment lines), but a
+    /// document parsed via [`Sentence::from_conllu`] round-trips its token
+    /// rows, `DEPS` and multiword-token/empty-node rows included (apart from
+    /// `FEATS` being re-sorted by key and a `_` `HEAD` coming back as `0`).
+    ///
+    /// # Example
+    /// ```
+    /// # use udpipe_rs::{Sentence, Word};
+    /// let sentence = Sentence {
+    ///     words: vec![Word {
+    ///         form: "Hi".to_string(), lemma: "hi".to_string(), upostag: "INTJ".to_string(),
+    ///         xpostag: String::new(), feats: String::new(), deprel: "root".to_string(),
+    ///         deps: String::new(), misc: String::new(), id: 1, head: 0, sentence_id: 0,
+    ///     }],
+    ///     raw_rows: Vec::new(),
+    /// };
+    /// assert_eq!(
+    ///     sentence.to_conllu(),
+    ///     "# sent_id = 1\n# text = Hi\n1\tHi\thi\tINTJ\t_\t_\t0\troot\t_\t_\n\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_conllu(&self) -> String {
+        let sent_id = self.words.first().map_or(0, |w| w.sentence_id) + 1;
+        let mut out = format!("# sent_id = {sent_id}\n# text = {}\n", self.text());
+        for (index, word) in self.words.iter().enumerate() {
+            for (_, raw_row) in self.raw_rows.iter().filter(|(at, _)| *at == index) {
+                out.push_str(raw_row);
+                out.push('\n');
+            }
+            out.push_str(&word.to_conllu());
+            out.push('\n');
+        }
+        for (_, raw_row) in self
+            .raw_rows
+            .iter()
+            .filter(|(at, _)| *at == self.words.len())
+        {
+            out.push_str(raw_row);
+            out.push('\n');
+        }
+        out.push('\n');
+        out
+    }
+
+    /// Parses a CoNLL-U document into its sentences.
+    ///
+    /// Round-trips losslessly through [`Sentence::to_conllu`]: multiword-token
+    /// rows (`1-2`) and empty/enhanced nodes with decimal IDs (`5.1`) aren't
+    /// representable by [`Word`]'s integer `id`, so they're kept verbatim in
+    /// [`Sentence::raw_rows`] instead and re-emitted at their original
+    /// position; the `DEPS` column is kept on [`Word::deps`]. Comment lines
+    /// (any line starting with `#`) are not preserved — `to_conllu` re-derives
+    /// only `# sent_id`/`# text`. `_` is treated as the missing-value
+    /// sentinel for every column other than `ID`; a `_` `HEAD` is read as `0`
+    /// and written back as `0`, and `FEATS` are written back sorted by key.
+    ///
+    /// # Errors
+    /// Returns an error if a row doesn't have ten tab-separated columns, a
+    /// token row's `ID` column isn't a valid integer, or its `HEAD` column
+    /// isn't `_` or a valid integer.
+    pub fn from_conllu(conllu: &str) -> Result<Vec<Sentence>, UdpipeError> {
+        let mut sentences = Vec::new();
+
+        let blocks = conllu
+            .split("\n\n")
+            .map(str::trim)
+            .filter(|block| !block.is_empty());
+
+        for (sentence_id, block) in blocks.enumerate() {
+            let mut words = Vec::new();
+            let mut raw_rows = Vec::new();
+
+            for line in block.lines() {
+                let line = line.trim_end_matches('\r');
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+
+                let columns: Vec<&str> = line.split('\t').collect();
+                if columns.len() != 10 {
+                    return Err(UdpipeError::new(format!(
+                        "expected 10 tab-separated CoNLL-U columns, found {}: {line}",
+                        columns.len()
+                    )));
+                }
+
+                // Multiword-token ranges ("1-2") and empty/enhanced nodes
+                // ("5.1") don't fit `Word::id: i32`; keep the row verbatim
+                // instead, positioned by how many words precede it.
+                if columns[0].contains('-') || columns[0].contains('.') {
+                    raw_rows.push((words.len(), line.to_string()));
+                    continue;
+                }
+
+                let field = |value: &str| {
+                    if value == "_" {
+                        String::new()
+                    } else {
+                        value.to_string()
+                    }
+                };
+
+                words.push(Word {
+                    form: field(columns[1]),
+                    lemma: field(columns[2]),
+                    upostag: field(columns[3]),
+                    xpostag: field(columns[4]),
+                    feats: field(columns[5]),
+                    deprel: field(columns[7]),
+                    deps: field(columns[8]),
+                    misc: field(columns[9]),
+                    id: columns[0].parse().map_err(|_| {
+                        UdpipeError::new(format!("invalid token ID: {}", columns[0]))
+                    })?,
+                    head: if columns[6] == "_" {
+                        0
+                    } else {
+                        columns[6].parse().map_err(|_| {
+                            UdpipeError::new(format!("invalid HEAD: {}", columns[6]))
+                        })?
+                    },
+                    sentence_id: sentence_id as i32,
+                });
+            }
+
+            if !words.is_empty() || !raw_rows.is_empty() {
+                sentences.push(Sentence { words, raw_rows });
+            }
+        }
+
+        Ok(sentences)
+    }
+
+    /// Builds a [`DependencyTree`] over this sentence's words.
+    ///
+    /// # Errors
+    /// Returns an error if the words don't form a single well-formed tree
+    /// (zero or multiple roots, a cycle, or a dangling head reference).
+    pub fn dependency_tree(&self) -> Result<DependencyTree<'_>, UdpipeError> {
+        DependencyTree::new(&self.words)
+    }
+}
+
+/// A dependency tree over a sentence's words, built from their `head`
+/// indices (`head == 0` marks the root).
+///
+/// This avoids re-deriving parent/child/subtree relationships from the flat
+/// `Vec<Word>` at every call site. A common use is splitting long documents
+/// into syntactically coherent chunks for retrieval/embedding pipelines: walk
+/// the subtree of each clause-heading word (e.g. one whose `deprel` is a
+/// clause relation) instead of cutting at a fixed window size.
+pub struct DependencyTree<'a> {
+    words: &'a [Word],
+}
+
+impl<'a> DependencyTree<'a> {
+    /// Builds a tree from a sentence's words.
+    ///
+    /// # Errors
+    /// Returns an error if `words` doesn't contain exactly one root
+    /// (`head == 0`), has a dangling head that doesn't match any word's
+    /// `id`, or contains a cycle.
+    pub fn new(words: &'a [Word]) -> Result<Self, UdpipeError> {
+        let root_count = words.iter().filter(|w| w.head == 0).count();
+        if root_count != 1 {
+            return Err(UdpipeError::new(format!(
+                "Expected exactly one root (head == 0), found {root_count}"
+            )));
+        }
+
+        let tree = DependencyTree { words };
+        tree.check_well_formed()?;
+        Ok(tree)
+    }
+
+    /// Walks every word's head chain up to the root, failing on a dangling
+    /// head or a cycle rather than recursing forever.
+    fn check_well_formed(&self) -> Result<(), UdpipeError> {
+        for word in self.words {
+            let mut current = word;
+            let mut steps = 0;
+            while current.head != 0 {
+                steps += 1;
+                if steps > self.words.len() {
+                    return Err(UdpipeError::new(format!(
+                        "Cycle detected in dependency tree at word id {}",
+                        word.id
+                    )));
+                }
+                current = self.word_by_id(current.head).ok_or_else(|| {
+                    UdpipeError::new(format!(
+                        "Word id {} has dangling head {}",
+                        current.id, current.head
+                    ))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn word_by_id(&self, id: i32) -> Option<&'a Word> {
+        self.words.iter().find(|w| w.id == id)
+    }
+
+    /// Returns the root word (`head == 0`).
+    #[must_use]
+    pub fn root(&self) -> &'a Word {
+        self.words
+            .iter()
+            .find(|w| w.head == 0)
+            .expect("validated by DependencyTree::new")
+    }
+
+    /// Returns the parent of the word with the given id, or `None` if it is
+    /// the root or `id` doesn't exist.
+    #[must_use]
+    pub fn parent(&self, id: i32) -> Option<&'a Word> {
+        let word = self.word_by_id(id)?;
+        if word.head == 0 {
+            None
+        } else {
+            self.word_by_id(word.head)
+        }
+    }
+
+    /// Returns the direct dependents of the word with the given id, in
+    /// surface order.
+    pub fn children(&self, id: i32) -> impl Iterator<Item = &'a Word> + '_ {
+        self.words.iter().filter(move |w| w.head == id)
+    }
+
+    /// Returns the word with the given id plus all of its transitive
+    /// dependents, in surface order.
+    pub fn subtree(&self, id: i32) -> impl Iterator<Item = &'a Word> + '_ {
+        let mut member_ids = std::collections::HashSet::new();
+        self.collect_subtree_ids(id, &mut member_ids);
+        self.words
+            .iter()
+            .filter(move |w| member_ids.contains(&w.id))
+    }
+
+    fn collect_subtree_ids(&self, id: i32, member_ids: &mut std::collections::HashSet<i32>) {
+        if !member_ids.insert(id) {
+            return;
+        }
+        for child in self.children(id) {
+            self.collect_subtree_ids(child.id, member_ids);
+        }
+    }
+
+    /// Reconstructs the original text span covered by the subtree rooted at
+    /// `id`, honoring each word's [`Word::space_after`] annotation.
+    #[must_use]
+    pub fn subtree_text(&self, id: i32) -> String {
+        let mut text = String::new();
+        for word in self.subtree(id) {
+            text.push_str(&word.form);
+            if word.space_after() {
+                text.push(' ');
+            }
+        }
+        text.trim_end().to_string()
+    }
 }
 
 // FFI declarations
@@ -193,6 +666,11 @@ mod ffi {
         _private: [u8; 0],
     }
 
+    #[repr(C)]
+    pub struct UdpipePipeline {
+        _private: [u8; 0],
+    }
+
     #[repr(C)]
     pub struct UdpipeWord {
         pub form: *const c_char,
@@ -211,16 +689,68 @@ mod ffi {
         pub fn udpipe_model_load(model_path: *const c_char) -> *mut UdpipeModel;
         pub fn udpipe_model_load_from_memory(data: *const u8, len: usize) -> *mut UdpipeModel;
         pub fn udpipe_model_free(model: *mut UdpipeModel);
-        pub fn udpipe_parse(model: *mut UdpipeModel, text: *const c_char)
-        -> *mut UdpipeParseResult;
+        pub fn udpipe_parse_conllu(
+            model: *mut UdpipeModel,
+            conllu_text: *const c_char,
+        ) -> *mut UdpipeParseResult;
+        pub fn udpipe_tokenize(
+            model: *mut UdpipeModel,
+            text: *const c_char,
+        ) -> *mut UdpipeParseResult;
+        pub fn udpipe_parse_with_format(
+            model: *mut UdpipeModel,
+            input_format: *const c_char,
+            text: *const c_char,
+        ) -> *mut UdpipeParseResult;
+        pub fn udpipe_tag(
+            model: *mut UdpipeModel,
+            conllu_text: *const c_char,
+        ) -> *mut UdpipeParseResult;
+        pub fn udpipe_parse_dependencies(
+            model: *mut UdpipeModel,
+            conllu_text: *const c_char,
+        ) -> *mut UdpipeParseResult;
         pub fn udpipe_result_free(result: *mut UdpipeParseResult);
+        /// Returns the last error set on this thread by a call into this
+        /// module, or null. Every Rust call site in this crate reads this
+        /// immediately after the FFI call that might have set it, so the
+        /// wrapper's error state must be thread-local (or otherwise scoped
+        /// per-thread): a process-global error pointer would let one
+        /// thread's failure be read back by another thread's call to
+        /// [`get_ffi_error`], which is exactly the kind of cross-thread
+        /// contamination per-worker [`UdpipePipeline`]s exist to avoid
+        /// elsewhere in this file.
         pub fn udpipe_get_error() -> *const c_char;
         pub fn udpipe_result_word_count(result: *mut UdpipeParseResult) -> i32;
         pub fn udpipe_result_get_word(result: *mut UdpipeParseResult, index: i32) -> UdpipeWord;
+
+        /// Opens an independent tokenize/tag/parse pipeline over `model`'s
+        /// already-loaded, read-only dictionaries. Cheap: allocates only the
+        /// pipeline's own mutable working state, unlike `udpipe_model_load`.
+        /// Safe to call concurrently for the same `model` from multiple
+        /// threads, each with its own pipeline.
+        pub fn udpipe_pipeline_new(model: *mut UdpipeModel) -> *mut UdpipePipeline;
+        pub fn udpipe_pipeline_free(pipeline: *mut UdpipePipeline);
+        pub fn udpipe_pipeline_tokenize(
+            pipeline: *mut UdpipePipeline,
+            text: *const c_char,
+        ) -> *mut UdpipeParseResult;
+        pub fn udpipe_pipeline_tag(
+            pipeline: *mut UdpipePipeline,
+            conllu_text: *const c_char,
+        ) -> *mut UdpipeParseResult;
+        pub fn udpipe_pipeline_parse_dependencies(
+            pipeline: *mut UdpipePipeline,
+            conllu_text: *const c_char,
+        ) -> *mut UdpipeParseResult;
     }
 }
 
 /// Get the last error from the FFI layer, or return a default message.
+///
+/// Relies on `udpipe_get_error` tracking its error state per-thread (see the
+/// note on that declaration); this crate does not add its own
+/// synchronization around it.
 fn get_ffi_error(default: &str) -> String {
     unsafe {
         let err_ptr = ffi::udpipe_get_error();
@@ -232,6 +762,41 @@ fn get_ffi_error(default: &str) -> String {
     }
 }
 
+/// Selects which UDPipe input reader segments/tokenizes text before tagging
+/// and dependency parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputFormat {
+    /// Run the tokenizer on raw, unsegmented text. The default.
+    #[default]
+    Tokenizer,
+    /// Whitespace-separated, pre-tokenized words, one sentence per line.
+    Horizontal,
+    /// One token per line; a blank line ends a sentence.
+    Vertical,
+    /// An existing CoNLL-U document; only columns left as `_` are filled in.
+    Conllu,
+}
+
+impl InputFormat {
+    /// The format identifier UDPipe's input readers expect.
+    fn as_str(self) -> &'static str {
+        match self {
+            InputFormat::Tokenizer => "tokenize",
+            InputFormat::Horizontal => "horizontal",
+            InputFormat::Vertical => "vertical",
+            InputFormat::Conllu => "conllu",
+        }
+    }
+}
+
+/// Options controlling how [`Model::parse_with_options`] and
+/// [`Model::parser`] interpret their input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Which input reader segments/tokenizes the input.
+    pub input_format: InputFormat,
+}
+
 /// UDPipe model wrapper.
 ///
 /// This is the main type for loading and using UDPipe models.
@@ -240,19 +805,72 @@ pub struct Model {
     inner: *mut ffi::UdpipeModel,
 }
 
-// SAFETY: The UDPipe model is thread-safe for parsing
+// SAFETY: `Model`'s dictionaries and weights are read-only once loaded;
+// `inner` is never mutated and never freed until `Drop`. Concurrent access
+// from multiple threads (as `Model::parse_batch` and `ParallelParser` do)
+// only ever opens a [`Pipeline`] from it, which hands back its own
+// `UdpipePipeline*` carrying that worker's mutable tokenizer/tagger scratch
+// state; the shared `Model*` itself is never touched concurrently by two
+// pipelines' tokenize/tag/parse calls.
 unsafe impl Send for Model {}
 unsafe impl Sync for Model {}
 
 impl Model {
-    /// Load a model from a file path.
+    /// Load a model from a file path, or by language identifier.
+    ///
+    /// If `path` isn't a file that exists, but matches a recognized entry in
+    /// [`AVAILABLE_MODELS`] (e.g. `"english-ewt"`), this transparently
+    /// resolves it through the checksum-verified cache instead of failing:
+    /// see [`download_model_cached`] for exactly what that means (no network
+    /// access once a verified copy is cached, a resumed download
+    /// otherwise).
     ///
     /// # Example
     /// ```no_run
     /// use udpipe_rs::Model;
     /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load model");
+    /// let model = Model::load("english-ewt").expect("Failed to load model"); // hits the cache
     /// ```
     pub fn load(path: impl AsRef<Path>) -> Result<Self, UdpipeError> {
+        let path = path.as_ref();
+
+        if !path.exists() {
+            if let Some(language) = path.to_str() {
+                if AVAILABLE_MODELS.contains(&language) {
+                    let cached_path = download_model_cached(language)?;
+                    return Self::load_from_path(cached_path);
+                }
+            }
+        }
+
+        Self::load_from_path(path)
+    }
+
+    /// Loads by language identifier, transparently using a checksum-verified
+    /// cached copy instead of requiring the caller to resolve a file path
+    /// themselves.
+    ///
+    /// This is now just [`Model::load`] under a more explicit name: `load`
+    /// resolves a recognized language identifier the same way. Kept for
+    /// callers who want it clear at the call site that no file path is
+    /// involved.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use udpipe_rs::Model;
+    /// let model = Model::load_cached("english-ewt").expect("Failed to load model");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if `language` isn't recognized, the download fails,
+    /// or the cached model file can't be loaded.
+    pub fn load_cached(language: &str) -> Result<Self, UdpipeError> {
+        Self::load(language)
+    }
+
+    /// Opens a model file directly, without consulting the language cache.
+    /// Shared by [`Model::load`]'s direct-path and cache-resolved cases.
+    fn load_from_path(path: impl AsRef<Path>) -> Result<Self, UdpipeError> {
         let path_str = path.as_ref().to_string_lossy();
         let c_path = CString::new(path_str.as_bytes()).map_err(|_| UdpipeError {
             message: "Invalid path (contains null byte)".to_string(),
@@ -291,55 +909,858 @@ impl Model {
         Ok(Model { inner: model })
     }
 
+    /// Opens an independent tokenize/tag/parse pipeline over this model's
+    /// already-loaded dictionaries, without re-reading or re-parsing the
+    /// model itself.
+    ///
+    /// This is cheap relative to [`Model::load`]: it only allocates the
+    /// pipeline's own mutable working state, reusing `self`'s read-only
+    /// data. Used by [`Model::parse_batch`] and [`ParallelParser`] to give
+    /// each worker thread its own pipeline over one shared, single loaded
+    /// `Model`, instead of calling tokenize/tag/parse on one `UdpipeModel*`
+    /// from multiple threads at once (or, worse, reloading the whole model
+    /// per thread).
+    ///
+    /// # Errors
+    /// Returns an error if the underlying pipeline can't be created.
+    pub fn pipeline(&self) -> Result<Pipeline<'_>, UdpipeError> {
+        let inner = unsafe { ffi::udpipe_pipeline_new(self.inner) };
+
+        if inner.is_null() {
+            return Err(UdpipeError {
+                message: get_ffi_error("Failed to create pipeline"),
+            });
+        }
+
+        Ok(Pipeline {
+            inner,
+            _model: std::marker::PhantomData,
+        })
+    }
+
     /// Parse text and return all words with their UD annotations.
     ///
-    /// The text is tokenized, tagged, lemmatized, and parsed for dependencies.
+    /// With the default [`InputFormat::Tokenizer`], this runs the full
+    /// three-stage pipeline: [`Model::tokenize`], [`Model::tag`], and
+    /// [`Model::parse_dependencies`]. Use those stages directly to supply
+    /// gold tokenization, swap in an external tagger, or stop short of
+    /// dependency parsing. Passing any other `options.input_format` instead
+    /// routes through [`Model::parse_with_options`], skipping the tokenizer
+    /// in favor of the requested reader, and flattens the resulting
+    /// sentences into one `Vec<Word>`.
     ///
     /// # Example
     /// ```no_run
-    /// use udpipe_rs::Model;
+    /// use udpipe_rs::{Model, ParseOptions};
     /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load");
-    /// let words = model.parse("The quick brown fox.").expect("Failed to parse");
+    /// let words = model.parse("The quick brown fox.", ParseOptions::default()).expect("Failed to parse");
     /// for word in words {
     ///     println!("{} -> {} ({})", word.form, word.lemma, word.upostag);
     /// }
     /// ```
-    pub fn parse(&self, text: &str) -> Result<Vec<Word>, UdpipeError> {
+    pub fn parse(&self, text: &str, options: ParseOptions) -> Result<Vec<Word>, UdpipeError> {
+        if options.input_format != InputFormat::Tokenizer {
+            let sentences = self.parse_with_options(text, options)?;
+            return Ok(sentences
+                .into_iter()
+                .flat_map(|sentence| sentence.words)
+                .collect());
+        }
+
+        let tokens = self.tokenize(text)?;
+        let mut words: Vec<Word> = tokens.into_iter().map(Token::into_word).collect();
+        self.tag(&mut words)?;
+        self.parse_dependencies(&mut words)?;
+        Ok(words)
+    }
+
+    /// Segment and tokenize raw text, without tagging or parsing.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use udpipe_rs::Model;
+    /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load");
+    /// let tokens = model.tokenize("The quick brown fox.").expect("Failed to tokenize");
+    /// ```
+    pub fn tokenize(&self, text: &str) -> Result<Vec<Token>, UdpipeError> {
         let c_text = CString::new(text).map_err(|_| UdpipeError {
             message: "Invalid text (contains null byte)".to_string(),
         })?;
 
-        let result = unsafe { ffi::udpipe_parse(self.inner, c_text.as_ptr()) };
+        let result = unsafe { ffi::udpipe_tokenize(self.inner, c_text.as_ptr()) };
 
         if result.is_null() {
             return Err(UdpipeError {
-                message: get_ffi_error("Failed to parse text"),
+                message: get_ffi_error("Failed to tokenize text"),
             });
         }
 
-        let word_count = unsafe { ffi::udpipe_result_word_count(result) };
-        let mut words = Vec::with_capacity(word_count as usize);
-
-        for i in 0..word_count {
-            let word = unsafe { ffi::udpipe_result_get_word(result, i) };
-            words.push(Word {
-                form: unsafe { CStr::from_ptr(word.form).to_string_lossy().into_owned() },
-                lemma: unsafe { CStr::from_ptr(word.lemma).to_string_lossy().into_owned() },
-                upostag: unsafe { CStr::from_ptr(word.upostag).to_string_lossy().into_owned() },
-                xpostag: unsafe { CStr::from_ptr(word.xpostag).to_string_lossy().into_owned() },
-                feats: unsafe { CStr::from_ptr(word.feats).to_string_lossy().into_owned() },
-                deprel: unsafe { CStr::from_ptr(word.deprel).to_string_lossy().into_owned() },
-                misc: unsafe { CStr::from_ptr(word.misc).to_string_lossy().into_owned() },
-                id: word.id,
-                head: word.head,
-                sentence_id: word.sentence_id,
+        let words = unsafe { words_from_result(result) };
+        unsafe { ffi::udpipe_result_free(result) };
+
+        Ok(words.into_iter().map(Token::from).collect())
+    }
+
+    /// Fill in POS tags, lemmas, and morphological features for
+    /// already-tokenized words, in place.
+    ///
+    /// `deprel`, `head`, and `misc` are left untouched; only the tagging
+    /// columns (`lemma`, `upostag`, `xpostag`, `feats`) are overwritten.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use udpipe_rs::{Model, Token};
+    /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load");
+    /// let tokens = model.tokenize("The quick brown fox.").expect("Failed to tokenize");
+    /// let mut words: Vec<_> = tokens.into_iter().map(Token::into_word).collect();
+    /// model.tag(&mut words).expect("Failed to tag");
+    /// ```
+    pub fn tag(&self, words: &mut [Word]) -> Result<(), UdpipeError> {
+        let conllu = words_to_conllu(words)?;
+
+        let result = unsafe { ffi::udpipe_tag(self.inner, conllu.as_ptr()) };
+
+        if result.is_null() {
+            return Err(UdpipeError {
+                message: get_ffi_error("Failed to tag words"),
+            });
+        }
+
+        let tagged = unsafe { words_from_result(result) };
+        unsafe { ffi::udpipe_result_free(result) };
+
+        apply_tagging(words, &tagged)?;
+        Ok(())
+    }
+
+    /// Attach dependency relations (`head`, `deprel`) for already-tagged
+    /// words, in place.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use udpipe_rs::{Model, Token};
+    /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load");
+    /// let tokens = model.tokenize("The quick brown fox.").expect("Failed to tokenize");
+    /// let mut words: Vec<_> = tokens.into_iter().map(Token::into_word).collect();
+    /// model.tag(&mut words).expect("Failed to tag");
+    /// model.parse_dependencies(&mut words).expect("Failed to parse dependencies");
+    /// ```
+    pub fn parse_dependencies(&self, words: &mut [Word]) -> Result<(), UdpipeError> {
+        let conllu = words_to_conllu(words)?;
+
+        let result = unsafe { ffi::udpipe_parse_dependencies(self.inner, conllu.as_ptr()) };
+
+        if result.is_null() {
+            return Err(UdpipeError {
+                message: get_ffi_error("Failed to parse dependencies"),
+            });
+        }
+
+        let parsed = unsafe { words_from_result(result) };
+        unsafe { ffi::udpipe_result_free(result) };
+
+        apply_dependencies(words, &parsed)?;
+        Ok(())
+    }
+
+    /// Parse pre-tokenized/pre-tagged CoNLL-U input, running only whichever
+    /// stages (tagging, parsing) are needed to fill in columns the input
+    /// left as `_`.
+    ///
+    /// This lets callers feed gold tokenization (or an entire gold-annotated
+    /// treebank) through the pipeline without the tokenizer re-segmenting
+    /// text it has already split.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use udpipe_rs::Model;
+    /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load");
+    /// let conllu = "# text = Hi\n1\tHi\t_\t_\t_\t_\t_\t_\t_\t_\n\n";
+    /// let sentences = model.parse_from_conllu(conllu).expect("Failed to parse");
+    /// ```
+    pub fn parse_from_conllu(&self, conllu: &str) -> Result<Vec<Sentence>, UdpipeError> {
+        let c_text = CString::new(conllu).map_err(|_| UdpipeError {
+            message: "Invalid CoNLL-U input (contains null byte)".to_string(),
+        })?;
+
+        let result = unsafe { ffi::udpipe_parse_conllu(self.inner, c_text.as_ptr()) };
+
+        if result.is_null() {
+            return Err(UdpipeError {
+                message: get_ffi_error("Failed to parse CoNLL-U input"),
+            });
+        }
+
+        let words = unsafe { words_from_result(result) };
+        unsafe { ffi::udpipe_result_free(result) };
+
+        Ok(Sentence::group(words))
+    }
+
+    /// Parses `input`, interpreting it according to `options.input_format`
+    /// instead of always running the tokenizer.
+    ///
+    /// This is the general entry point behind [`Model::parse`] and
+    /// [`Model::parser`]: it lets callers who already have whitespace- or
+    /// line-tokenized text, or an existing CoNLL-U document, skip straight to
+    /// tagging and parsing without the tokenizer re-segmenting input that's
+    /// already split.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use udpipe_rs::{InputFormat, Model, ParseOptions};
+    /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load");
+    /// let sentences = model
+    ///     .parse_with_options(
+    ///         "The quick brown fox .",
+    ///         ParseOptions { input_format: InputFormat::Horizontal },
+    ///     )
+    ///     .expect("Failed to parse");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if `input` contains a null byte or the underlying
+    /// UDPipe pipeline fails.
+    pub fn parse_with_options(
+        &self,
+        input: &str,
+        options: ParseOptions,
+    ) -> Result<Vec<Sentence>, UdpipeError> {
+        let c_text = CString::new(input).map_err(|_| UdpipeError {
+            message: "Invalid input (contains null byte)".to_string(),
+        })?;
+        let c_format = CString::new(options.input_format.as_str())
+            .expect("input format strings never contain null bytes");
+
+        let result = unsafe {
+            ffi::udpipe_parse_with_format(self.inner, c_format.as_ptr(), c_text.as_ptr())
+        };
+
+        if result.is_null() {
+            return Err(UdpipeError {
+                message: get_ffi_error("Failed to parse input"),
             });
         }
 
+        let words = unsafe { words_from_result(result) };
         unsafe { ffi::udpipe_result_free(result) };
 
+        Ok(Sentence::group(words))
+    }
+
+    /// Parses `text` into an iterator of sentences, interpreting it
+    /// according to `options.input_format`.
+    ///
+    /// Unlike [`Model::parse`], which returns a flat `Vec<Word>`, this
+    /// returns already-grouped [`Sentence`] values. This is a thin wrapper
+    /// around [`Model::parse_with_options`] that adapts its `Vec<Sentence>`
+    /// into an iterator.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use udpipe_rs::{InputFormat, Model, ParseOptions};
+    /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load");
+    /// let sentences: Vec<_> = model
+    ///     .parser("The quick brown fox .", ParseOptions { input_format: InputFormat::Horizontal })
+    ///     .expect("Failed to create parser")
+    ///     .collect::<Result<_, _>>()
+    ///     .expect("Failed to parse");
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if `text` contains a null byte or the underlying
+    /// UDPipe pipeline fails.
+    pub fn parser(
+        &self,
+        text: &str,
+        options: ParseOptions,
+    ) -> Result<impl Iterator<Item = Result<Sentence, UdpipeError>>, UdpipeError> {
+        let sentences = self.parse_with_options(text, options)?;
+        Ok(sentences.into_iter().map(Ok))
+    }
+
+    /// Streams sentences from `reader`, using the default read buffer size
+    /// (64 KiB). See [`Model::parser_from_reader_with_capacity`] for details
+    /// and to configure the buffer size.
+    #[must_use]
+    pub fn parser_from_reader<R: Read>(&self, reader: R) -> ReaderParser<'_, R> {
+        self.parser_from_reader_with_capacity(reader, DEFAULT_READER_BUFFER_SIZE)
+    }
+
+    /// Streams sentences from `reader`, tokenizing/tagging/parsing complete
+    /// sentences as they're found rather than buffering the whole input.
+    ///
+    /// Bytes are pulled in chunks of `buffer_size`; peak memory stays
+    /// proportional to the longest sentence in the input, not its total
+    /// size. A sentence that straddles a chunk boundary is handled by
+    /// retaining its raw, not-yet-consumed input text and prepending it to
+    /// the next chunk rather than emitting it prematurely.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use udpipe_rs::Model;
+    /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load");
+    /// let file = std::fs::File::open("corpus.txt").expect("Failed to open corpus");
+    /// for sentence in model.parser_from_reader_with_capacity(file, 1 << 20) {
+    ///     let sentence = sentence.expect("Failed to parse");
+    ///     println!("{}", sentence.text());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn parser_from_reader_with_capacity<R: Read>(
+        &self,
+        reader: R,
+        buffer_size: usize,
+    ) -> ReaderParser<'_, R> {
+        ReaderParser {
+            model: self,
+            reader,
+            // At least 4 bytes so a leftover incomplete UTF-8 sequence (at
+            // most 3 bytes) always still leaves room to read more.
+            buffer: vec![0u8; buffer_size.max(4)],
+            leftover: Vec::new(),
+            pending: String::new(),
+            ready: std::collections::VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Parses many independent documents in parallel, one worker per
+    /// available core (or one per document, whichever is smaller).
+    ///
+    /// Each worker opens its own [`Pipeline`] from this single, shared
+    /// `Model` rather than calling tokenize/tag/parse on `self`'s
+    /// `UdpipeModel*` from multiple threads at once — the model's
+    /// dictionaries stay loaded exactly once no matter how many workers run.
+    ///
+    /// The output preserves the input order, and a failure on one document
+    /// is isolated to its own `Result` rather than aborting the batch. This
+    /// is the entry point for corpus-scale processing: the model stays
+    /// loaded once while work fans out across threads.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use udpipe_rs::Model;
+    /// let model = Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load");
+    /// let results = model.parse_batch(&["Hello world!", "Goodbye."]);
+    /// for result in results {
+    ///     let words = result.expect("Failed to parse");
+    ///     println!("{} words", words.len());
+    /// }
+    /// ```
+    pub fn parse_batch(&self, texts: &[&str]) -> Vec<Result<Vec<Word>, UdpipeError>> {
+        if texts.is_empty() {
+            return Vec::new();
+        }
+
+        let worker_count = std::thread::available_parallelism()
+            .map(std::num::NonZeroUsize::get)
+            .unwrap_or(1)
+            .min(texts.len());
+
+        let next_index = std::sync::atomic::AtomicUsize::new(0);
+        let results: Vec<Option<Result<Vec<Word>, UdpipeError>>> =
+            (0..texts.len()).map(|_| None).collect();
+        let results = std::sync::Mutex::new(results);
+
+        std::thread::scope(|scope| {
+            for _ in 0..worker_count {
+                scope.spawn(|| {
+                    let claim_next =
+                        || next_index.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+                    // Each worker gets its own pipeline over the one shared
+                    // model; a failure to open one fails only this worker's
+                    // share of the batch, not the whole call.
+                    let pipeline = match self.pipeline() {
+                        Ok(pipeline) => pipeline,
+                        Err(e) => loop {
+                            let index = claim_next();
+                            if index >= texts.len() {
+                                return;
+                            }
+                            results.lock().expect("results mutex poisoned")[index] =
+                                Some(Err(e.clone()));
+                        },
+                    };
+
+                    loop {
+                        let index = claim_next();
+                        if index >= texts.len() {
+                            break;
+                        }
+                        let result = pipeline.parse(texts[index]);
+                        results.lock().expect("results mutex poisoned")[index] = Some(result);
+                    }
+                });
+            }
+        });
+
+        results
+            .into_inner()
+            .expect("results mutex poisoned")
+            .into_iter()
+            .map(|slot| slot.expect("every index is assigned exactly once"))
+            .collect()
+    }
+}
+
+/// An independent tokenize/tag/parse pipeline opened from a shared
+/// [`Model`] via [`Model::pipeline`].
+///
+/// Holds its own mutable working state (tokenizer/tagger scratch buffers)
+/// over the parent `Model`'s read-only dictionaries, so one `Model` can have
+/// many `Pipeline`s open across threads at once without reloading or
+/// racing. Borrows the `Model` it was created from, so it cannot outlive it.
+pub struct Pipeline<'a> {
+    inner: *mut ffi::UdpipePipeline,
+    _model: std::marker::PhantomData<&'a Model>,
+}
+
+// SAFETY: a `Pipeline`'s mutable scratch state is exclusively owned by
+// whichever thread holds it; nothing else can reach the same
+// `UdpipePipeline*`, so moving one to another thread is safe. It is not
+// `Sync`: its tokenize/tag/parse calls mutate that scratch state, so two
+// threads must not call through the same `&Pipeline` concurrently.
+unsafe impl Send for Pipeline<'_> {}
+
+impl Pipeline<'_> {
+    /// Parse text and return all words with their UD annotations. See
+    /// [`Model::parse`].
+    pub fn parse(&self, text: &str) -> Result<Vec<Word>, UdpipeError> {
+        let tokens = self.tokenize(text)?;
+        let mut words: Vec<Word> = tokens.into_iter().map(Token::into_word).collect();
+        self.tag(&mut words)?;
+        self.parse_dependencies(&mut words)?;
         Ok(words)
     }
+
+    /// Segment and tokenize raw text, without tagging or parsing. See
+    /// [`Model::tokenize`].
+    pub fn tokenize(&self, text: &str) -> Result<Vec<Token>, UdpipeError> {
+        let c_text = CString::new(text).map_err(|_| UdpipeError {
+            message: "Invalid text (contains null byte)".to_string(),
+        })?;
+
+        let result = unsafe { ffi::udpipe_pipeline_tokenize(self.inner, c_text.as_ptr()) };
+
+        if result.is_null() {
+            return Err(UdpipeError {
+                message: get_ffi_error("Failed to tokenize text"),
+            });
+        }
+
+        let words = unsafe { words_from_result(result) };
+        unsafe { ffi::udpipe_result_free(result) };
+
+        Ok(words.into_iter().map(Token::from).collect())
+    }
+
+    /// Fill in POS tags, lemmas, and morphological features for
+    /// already-tokenized words, in place. See [`Model::tag`].
+    pub fn tag(&self, words: &mut [Word]) -> Result<(), UdpipeError> {
+        let conllu = words_to_conllu(words)?;
+
+        let result = unsafe { ffi::udpipe_pipeline_tag(self.inner, conllu.as_ptr()) };
+
+        if result.is_null() {
+            return Err(UdpipeError {
+                message: get_ffi_error("Failed to tag words"),
+            });
+        }
+
+        let tagged = unsafe { words_from_result(result) };
+        unsafe { ffi::udpipe_result_free(result) };
+
+        apply_tagging(words, &tagged)?;
+        Ok(())
+    }
+
+    /// Attach dependency relations (`head`, `deprel`) for already-tagged
+    /// words, in place. See [`Model::parse_dependencies`].
+    pub fn parse_dependencies(&self, words: &mut [Word]) -> Result<(), UdpipeError> {
+        let conllu = words_to_conllu(words)?;
+
+        let result =
+            unsafe { ffi::udpipe_pipeline_parse_dependencies(self.inner, conllu.as_ptr()) };
+
+        if result.is_null() {
+            return Err(UdpipeError {
+                message: get_ffi_error("Failed to parse dependencies"),
+            });
+        }
+
+        let parsed = unsafe { words_from_result(result) };
+        unsafe { ffi::udpipe_result_free(result) };
+
+        apply_dependencies(words, &parsed)?;
+        Ok(())
+    }
+}
+
+impl Drop for Pipeline<'_> {
+    fn drop(&mut self) {
+        if !self.inner.is_null() {
+            unsafe { ffi::udpipe_pipeline_free(self.inner) };
+        }
+    }
+}
+
+/// Parses a single document's sentences in parallel over a shared,
+/// reference-counted [`Model`].
+///
+/// The loaded model data is read-only and safely shared across threads; only
+/// the per-call tagging/parsing buffers are mutable, and those are owned by
+/// each worker rather than shared. Where [`Model::parse_batch`] fans
+/// multiple independent documents across threads, `ParallelParser` fans out
+/// the sentences of one document, which helps when a single large document
+/// dominates a batch.
+pub struct ParallelParser {
+    model: std::sync::Arc<Model>,
+}
+
+impl ParallelParser {
+    /// Wraps a shared model for parallel, sentence-level parsing.
+    #[must_use]
+    pub fn new(model: std::sync::Arc<Model>) -> Self {
+        Self { model }
+    }
+
+    /// Tokenizes `text` to find sentence boundaries, then tags and
+    /// dependency-parses each sentence on a worker pool sized to the
+    /// available parallelism, reassembling the result in original order.
+    ///
+    /// Tokenizing happens once against the shared model, but each worker
+    /// opens its own [`Pipeline`] from it for tagging/parsing, rather than
+    /// calling into the shared `UdpipeModel*` from multiple threads at once
+    /// — the model stays loaded exactly once regardless of worker count.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::sync::Arc;
+    /// use udpipe_rs::{Model, ParallelParser};
+    ///
+    /// let model = Arc::new(Model::load("english-ewt-ud-2.5-191206.udpipe").expect("Failed to load"));
+    /// let parser = ParallelParser::new(model);
+    /// let sentences = parser
+    ///     .par_parse("The cat sat. The dog ran.")
+    ///     .expect("Failed to parse");
+    /// ```
+    pub fn par_parse(&self, text: &str) -> Result<Vec<Sentence>, UdpipeError> {
+        let tokens = self.model.tokenize(text)?;
+        let words: Vec<Word> = tokens.into_iter().map(Token::into_word).collect();
+        let sentences: Vec<Vec<Word>> = Sentence::group(words)
+            .into_iter()
+            .map(|sentence| sentence.words)
+            .collect();
+
+        if sentences.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let worker_count = std::thread::available_parallelism()
+            .map(std::num::NonZeroUsize::get)
+            .unwrap_or(1)
+            .min(sentences.len());
+
+        let next_index = std::sync::atomic::AtomicUsize::new(0);
+        let results: Vec<Option<Result<Vec<Word>, UdpipeError>>> =
+            (0..sentences.len()).map(|_| None).collect();
+        let results = std::sync::Mutex::new(results);
+
+        std::thread::scope(|scope| {
+            for _ in 0..worker_count {
+                scope.spawn(|| {
+                    let claim_next =
+                        || next_index.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+                    // Each worker gets its own pipeline over the one shared
+                    // model; a failure to open one fails only this worker's
+                    // share of the sentences, not the whole call.
+                    let pipeline = match self.model.pipeline() {
+                        Ok(pipeline) => pipeline,
+                        Err(e) => loop {
+                            let index = claim_next();
+                            if index >= sentences.len() {
+                                return;
+                            }
+                            results.lock().expect("results mutex poisoned")[index] =
+                                Some(Err(e.clone()));
+                        },
+                    };
+
+                    loop {
+                        let index = claim_next();
+                        if index >= sentences.len() {
+                            break;
+                        }
+                        let result = tag_and_parse(&pipeline, sentences[index].clone());
+                        results.lock().expect("results mutex poisoned")[index] = Some(result);
+                    }
+                });
+            }
+        });
+
+        results
+            .into_inner()
+            .expect("results mutex poisoned")
+            .into_iter()
+            .map(|slot| slot.expect("every index is assigned exactly once"))
+            .map(|words| {
+                words.map(|words| Sentence {
+                    words,
+                    raw_rows: Vec::new(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Tags and dependency-parses a single sentence's words, in place, returning
+/// them back to the caller once both stages succeed.
+fn tag_and_parse(pipeline: &Pipeline<'_>, mut words: Vec<Word>) -> Result<Vec<Word>, UdpipeError> {
+    pipeline.tag(&mut words)?;
+    pipeline.parse_dependencies(&mut words)?;
+    Ok(words)
+}
+
+/// Default chunk size used by [`Model::parser_from_reader`].
+const DEFAULT_READER_BUFFER_SIZE: usize = 64 * 1024;
+
+/// Iterator returned by [`Model::parser_from_reader`] and
+/// [`Model::parser_from_reader_with_capacity`].
+///
+/// Pulls bytes from `reader` in bounded chunks and tokenizes the
+/// accumulated, not-yet-emitted text after every read. Whenever that yields
+/// more than one sentence, every sentence but the last is known to be
+/// complete (it's followed by another sentence) and is queued for emission;
+/// the last one might still be a prefix of a longer sentence split across
+/// the chunk boundary, so its text is kept and re-tokenized together with
+/// the next chunk instead.
+///
+/// Locating that boundary relies on each emitted word's form appearing
+/// literally in the raw input (see [`Self::raw_consumed_len`]), which isn't
+/// true for languages whose tokenizer expands a single surface token into
+/// several multiword-token components (e.g. Portuguese "do" -> "de"+"o");
+/// streaming such text through this reader can surface an error where
+/// [`Model::parser`] on the same text would succeed.
+pub struct ReaderParser<'a, R: Read> {
+    model: &'a Model,
+    reader: R,
+    buffer: Vec<u8>,
+    /// Bytes read but not yet valid UTF-8 (a multi-byte character split
+    /// across two reads), prepended to the next chunk.
+    leftover: Vec<u8>,
+    /// Decoded text not yet split off into a queued, complete sentence.
+    pending: String,
+    ready: std::collections::VecDeque<Sentence>,
+    done: bool,
+}
+
+impl<'a, R: Read> Iterator for ReaderParser<'a, R> {
+    type Item = Result<Sentence, UdpipeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(sentence) = self.ready.pop_front() {
+                return Some(Ok(sentence));
+            }
+
+            if self.done {
+                if self.pending.trim().is_empty() {
+                    return None;
+                }
+                return Some(self.flush_pending());
+            }
+
+            if let Err(e) = self.fill_pending() {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+impl<'a, R: Read> ReaderParser<'a, R> {
+    /// Reads one more chunk (prefixed with any `leftover` bytes from the
+    /// last read), tokenizes the updated `pending` text, and queues every
+    /// sentence but the last into `ready`.
+    fn fill_pending(&mut self) -> Result<(), UdpipeError> {
+        self.buffer[..self.leftover.len()].copy_from_slice(&self.leftover);
+        let read = self.reader.read(&mut self.buffer[self.leftover.len()..])?;
+        let available = &self.buffer[..self.leftover.len() + read];
+
+        if read == 0 {
+            // EOF: whatever's left over is invalid UTF-8 input, not a
+            // truncated chunk boundary.
+            let text = std::str::from_utf8(available)
+                .map_err(|_| UdpipeError::new("Invalid input (not valid UTF-8)"))?;
+            self.pending.push_str(text);
+            self.leftover.clear();
+            self.done = true;
+            return Ok(());
+        }
+
+        let valid_len = match std::str::from_utf8(available) {
+            Ok(_) => available.len(),
+            Err(e) => e.valid_up_to(),
+        };
+        self.pending
+            .push_str(std::str::from_utf8(&available[..valid_len]).expect("validated above"));
+        self.leftover = available[valid_len..].to_vec();
+
+        let mut sentences = self
+            .model
+            .parse_with_options(&self.pending, ParseOptions::default())?;
+        if sentences.len() > 1 {
+            sentences.pop().expect("len > 1");
+            // `sentences` now holds only the complete ones; keep the raw,
+            // unconsumed suffix of `pending` (everything after their last
+            // word) as the new `pending`, rather than the popped tail's
+            // reconstructed `Sentence::text()`. `text()` normalizes
+            // whitespace and trims, so using it here would drop the
+            // boundary whitespace between the last queued sentence and the
+            // tail whenever that whitespace fell on a chunk boundary.
+            let consumed = Self::raw_consumed_len(&self.pending, &sentences)?;
+            self.pending = self.pending[consumed..].to_string();
+            self.ready.extend(sentences);
+        }
+
+        Ok(())
+    }
+
+    /// Finds the byte offset in `text` just past the last word of the last
+    /// sentence in `sentences`. Used to split off the literal,
+    /// unreconstructed raw suffix still belonging to a not-yet-complete
+    /// sentence; see [`Self::fill_pending`].
+    ///
+    /// Walks `text` and the words in lockstep, anchoring each word's form at
+    /// the next non-whitespace position instead of searching the rest of
+    /// `text` for it: `str::find` can match a coincidental, wrong occurrence
+    /// of a short or common form (e.g. `"a"`, `"."`) anywhere in the
+    /// not-yet-consumed text, silently under-advancing the cursor and
+    /// garbling or duplicating whatever chunk boundary follows. Returns an
+    /// error instead of a wrong cursor if a word's form isn't where it
+    /// should be (the tokenizer rewrote characters from the raw input, e.g.
+    /// normalized quotes), since guessing at that point would corrupt
+    /// output rather than just fail loudly.
+    fn raw_consumed_len(text: &str, sentences: &[Sentence]) -> Result<usize, UdpipeError> {
+        let mut cursor = 0;
+        for sentence in sentences {
+            for word in &sentence.words {
+                cursor += text[cursor..]
+                    .find(|c: char| !c.is_whitespace())
+                    .unwrap_or(0);
+                if !text[cursor..].starts_with(word.form.as_str()) {
+                    return Err(UdpipeError::new(format!(
+                        "tokenized form {:?} doesn't match raw input at byte offset {cursor}; \
+                         can't determine a safe chunk boundary",
+                        word.form
+                    )));
+                }
+                cursor += word.form.len();
+            }
+        }
+        Ok(cursor)
+    }
+
+    /// Parses and emits whatever text remains once the reader is exhausted.
+    fn flush_pending(&mut self) -> Result<Sentence, UdpipeError> {
+        let sentences = self
+            .model
+            .parse_with_options(&self.pending, ParseOptions::default())?;
+        self.pending.clear();
+        self.ready.extend(sentences);
+        self.ready
+            .pop_front()
+            .ok_or_else(|| UdpipeError::new("No sentence found in remaining input"))
+    }
+}
+
+/// Converts every word in an FFI parse result into owned [`Word`] values.
+///
+/// # Safety
+/// `result` must be a valid, non-null `UdpipeParseResult` pointer.
+unsafe fn words_from_result(result: *mut ffi::UdpipeParseResult) -> Vec<Word> {
+    let word_count = unsafe { ffi::udpipe_result_word_count(result) };
+    let mut words = Vec::with_capacity(word_count as usize);
+
+    for i in 0..word_count {
+        let word = unsafe { ffi::udpipe_result_get_word(result, i) };
+        words.push(Word {
+            form: unsafe { CStr::from_ptr(word.form).to_string_lossy().into_owned() },
+            lemma: unsafe { CStr::from_ptr(word.lemma).to_string_lossy().into_owned() },
+            upostag: unsafe { CStr::from_ptr(word.upostag).to_string_lossy().into_owned() },
+            xpostag: unsafe { CStr::from_ptr(word.xpostag).to_string_lossy().into_owned() },
+            feats: unsafe { CStr::from_ptr(word.feats).to_string_lossy().into_owned() },
+            deprel: unsafe { CStr::from_ptr(word.deprel).to_string_lossy().into_owned() },
+            deps: String::new(),
+            misc: unsafe { CStr::from_ptr(word.misc).to_string_lossy().into_owned() },
+            id: word.id,
+            head: word.head,
+            sentence_id: word.sentence_id,
+        });
+    }
+
+    words
+}
+
+/// Serializes words (grouped back into sentences by `sentence_id`) into a
+/// CoNLL-U document suitable for feeding to a `tag`/`parse_dependencies` FFI
+/// call.
+fn words_to_conllu(words: &[Word]) -> Result<CString, UdpipeError> {
+    let conllu: String = Sentence::group(words.to_vec())
+        .iter()
+        .map(Sentence::to_conllu)
+        .collect();
+
+    CString::new(conllu).map_err(|_| UdpipeError {
+        message: "Invalid word text (contains null byte)".to_string(),
+    })
+}
+
+/// Copies the tagging columns (`lemma`, `upostag`, `xpostag`, `feats`) from
+/// `tagged` onto `words`, in place.
+fn apply_tagging(words: &mut [Word], tagged: &[Word]) -> Result<(), UdpipeError> {
+    if words.len() != tagged.len() {
+        return Err(UdpipeError {
+            message: format!(
+                "Tagger returned {} words, expected {}",
+                tagged.len(),
+                words.len()
+            ),
+        });
+    }
+
+    for (word, tagged) in words.iter_mut().zip(tagged) {
+        word.lemma.clone_from(&tagged.lemma);
+        word.upostag.clone_from(&tagged.upostag);
+        word.xpostag.clone_from(&tagged.xpostag);
+        word.feats.clone_from(&tagged.feats);
+    }
+
+    Ok(())
+}
+
+/// Copies the dependency columns (`head`, `deprel`) from `parsed` onto
+/// `words`, in place.
+fn apply_dependencies(words: &mut [Word], parsed: &[Word]) -> Result<(), UdpipeError> {
+    if words.len() != parsed.len() {
+        return Err(UdpipeError {
+            message: format!(
+                "Parser returned {} words, expected {}",
+                parsed.len(),
+                words.len()
+            ),
+        });
+    }
+
+    for (word, parsed) in words.iter_mut().zip(parsed) {
+        word.head = parsed.head;
+        word.deprel.clone_from(&parsed.deprel);
+    }
+
+    Ok(())
 }
 
 impl Drop for Model {
@@ -503,70 +1924,311 @@ pub fn download_model(language: &str, dest_dir: impl AsRef<Path>) -> Result<Stri
     // Download using the generic download function
     download_model_from_url(&url, &dest_path)?;
 
-    Ok(dest_path.to_string_lossy().into_owned())
+    Ok(dest_path.to_string_lossy().into_owned())
+}
+
+/// Download a model from a custom URL to a local file path.
+///
+/// Use this if you need to download models from a different source or version.
+/// For standard models, prefer [`download_model`].
+///
+/// # Example
+///
+/// ```no_run
+/// use udpipe_rs::download_model_from_url;
+///
+/// download_model_from_url(
+///     "https://example.com/custom-model.udpipe",
+///     "custom-model.udpipe",
+/// ).expect("Failed to download");
+/// ```
+pub fn download_model_from_url(url: &str, path: impl AsRef<Path>) -> Result<(), UdpipeError> {
+    let path = path.as_ref();
+
+    // Create parent directories if needed
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+
+    // Download using ureq
+    let response = ureq::get(url).call().map_err(|e| UdpipeError {
+        message: format!("Failed to download: {}", e),
+    })?;
+
+    // Read response body
+    let mut data = Vec::new();
+    response
+        .into_body()
+        .into_reader()
+        .read_to_end(&mut data)
+        .map_err(|e| UdpipeError {
+            message: format!("Failed to read response: {}", e),
+        })?;
+
+    if data.is_empty() {
+        return Err(UdpipeError {
+            message: "Downloaded file is empty".to_string(),
+        });
+    }
+
+    // Write to file
+    std::fs::write(path, &data)?;
+
+    Ok(())
+}
+
+/// Returns the expected filename for a given language model.
+///
+/// # Example
+///
+/// ```
+/// assert_eq!(udpipe_rs::model_filename("english-ewt"), "english-ewt-ud-2.5-191206.udpipe");
+/// ```
+pub fn model_filename(language: &str) -> String {
+    format!("{}-ud-2.5-191206.udpipe", language)
+}
+
+/// Returns the cache directory for downloaded models, without creating it.
+///
+/// Resolves to `$XDG_CACHE_HOME/udpipe-rs/models` on Linux (falling back to
+/// `~/.cache` if unset), `~/Library/Caches/udpipe-rs/models` on macOS, and
+/// `%LOCALAPPDATA%\udpipe-rs\models` on Windows.
+fn cache_models_dir() -> Result<PathBuf, UdpipeError> {
+    Ok(platform_cache_base()?.join("udpipe-rs").join("models"))
+}
+
+#[cfg(target_os = "macos")]
+fn platform_cache_base() -> Result<PathBuf, UdpipeError> {
+    let home = std::env::var_os("HOME").ok_or_else(|| UdpipeError::new("HOME is not set"))?;
+    Ok(PathBuf::from(home).join("Library").join("Caches"))
+}
+
+#[cfg(target_os = "windows")]
+fn platform_cache_base() -> Result<PathBuf, UdpipeError> {
+    std::env::var_os("LOCALAPPDATA")
+        .map(PathBuf::from)
+        .ok_or_else(|| UdpipeError::new("LOCALAPPDATA is not set"))
 }
 
-/// Download a model from a custom URL to a local file path.
-///
-/// Use this if you need to download models from a different source or version.
-/// For standard models, prefer [`download_model`].
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn platform_cache_base() -> Result<PathBuf, UdpipeError> {
+    if let Some(xdg) = std::env::var_os("XDG_CACHE_HOME") {
+        return Ok(PathBuf::from(xdg));
+    }
+    let home = std::env::var_os("HOME").ok_or_else(|| UdpipeError::new("HOME is not set"))?;
+    Ok(PathBuf::from(home).join(".cache"))
+}
+
+/// Returns (creating it if necessary) the cache directory for downloaded
+/// models. See [`download_model_cached`].
 ///
 /// # Example
-///
 /// ```no_run
-/// use udpipe_rs::download_model_from_url;
+/// let dir = udpipe_rs::cache_dir().expect("Failed to resolve cache dir");
+/// println!("Models are cached under {}", dir.display());
+/// ```
+pub fn cache_dir() -> Result<PathBuf, UdpipeError> {
+    let dir = cache_models_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Removes every cached model (and its recorded checksum) from [`cache_dir`].
 ///
-/// download_model_from_url(
-///     "https://example.com/custom-model.udpipe",
-///     "custom-model.udpipe",
-/// ).expect("Failed to download");
+/// # Example
+/// ```no_run
+/// udpipe_rs::clear_cache().expect("Failed to clear cache");
 /// ```
-pub fn download_model_from_url(url: &str, path: impl AsRef<Path>) -> Result<(), UdpipeError> {
-    let path = path.as_ref();
+pub fn clear_cache() -> Result<(), UdpipeError> {
+    let dir = cache_models_dir()?;
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)?;
+    }
+    Ok(())
+}
 
-    // Create parent directories if needed
-    if let Some(parent) = path.parent() {
+/// Returns `path` with `suffix` appended to its file name, e.g. turning
+/// `model.udpipe` into `model.udpipe.sha256`.
+fn sibling_with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Computes the lowercase hex-encoded SHA-256 digest of `data`.
+fn sha256_hex(data: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Returns true if `model_path` exists and its contents match the SHA-256
+/// digest recorded alongside it by a previous [`download_model_cached`] call.
+///
+/// Hashing a multi-hundred-MB model on every call would add real IO and CPU
+/// to every process start, so this first compares against a `.meta` sidecar
+/// recording the file's size and modification time as of the last
+/// successful verification; only a mismatch (or no sidecar yet) triggers a
+/// full re-read and re-hash.
+fn is_cached_and_verified(model_path: &Path) -> bool {
+    let Ok(metadata) = std::fs::metadata(model_path) else {
+        return false;
+    };
+    let Some(fingerprint) = file_fingerprint(&metadata) else {
+        return false;
+    };
+
+    let meta_path = sibling_with_suffix(model_path, ".meta");
+    if std::fs::read_to_string(&meta_path).ok().as_deref() == Some(fingerprint.as_str()) {
+        return true;
+    }
+
+    let Ok(recorded) = std::fs::read_to_string(sibling_with_suffix(model_path, ".sha256")) else {
+        return false;
+    };
+    let Ok(data) = std::fs::read(model_path) else {
+        return false;
+    };
+    if sha256_hex(&data) != recorded.trim() {
+        return false;
+    }
+
+    // The full hash just confirmed `model_path` matches; record its
+    // fingerprint so the next call can skip straight to the fast path
+    // above, as long as the file doesn't change underneath it.
+    let _ = std::fs::write(&meta_path, &fingerprint);
+    true
+}
+
+/// Encodes a file's size and modification time as a cheap, comparable
+/// fingerprint for [`is_cached_and_verified`]'s fast path. Returns `None` if
+/// the platform can't report a modification time.
+fn file_fingerprint(metadata: &std::fs::Metadata) -> Option<String> {
+    let modified = metadata.modified().ok()?;
+    let since_epoch = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(format!("{}:{}", metadata.len(), since_epoch.as_nanos()))
+}
+
+/// Downloads `url` to `dest_path`, resuming from a `.partial` sidecar file
+/// left behind by an interrupted download, or overwriting it if the server
+/// doesn't honor the resume request.
+fn download_with_resume(url: &str, dest_path: &Path) -> Result<(), UdpipeError> {
+    if let Some(parent) = dest_path.parent() {
         if !parent.as_os_str().is_empty() {
             std::fs::create_dir_all(parent)?;
         }
     }
 
-    // Download using ureq
-    let response = ureq::get(url).call().map_err(|e| UdpipeError {
+    let partial_path = sibling_with_suffix(dest_path, ".partial");
+    let mut resume_from = std::fs::metadata(&partial_path).map_or(0, |m| m.len());
+
+    let mut request = ureq::get(url);
+    if resume_from > 0 {
+        request = request.header("Range", &format!("bytes={resume_from}-"));
+    }
+
+    let response = request.call().map_err(|e| UdpipeError {
         message: format!("Failed to download: {}", e),
     })?;
 
-    // Read response body
-    let mut data = Vec::new();
-    response
-        .into_body()
-        .into_reader()
-        .read_to_end(&mut data)
-        .map_err(|e| UdpipeError {
-            message: format!("Failed to read response: {}", e),
-        })?;
+    // A server that ignores our Range header sends the whole file back from
+    // the start; detect that and restart the partial file rather than
+    // appending mismatched data onto it.
+    if resume_from > 0 && response.status().as_u16() != 206 {
+        resume_from = 0;
+    }
 
-    if data.is_empty() {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(resume_from == 0)
+        .append(resume_from > 0)
+        .open(&partial_path)?;
+
+    std::io::copy(&mut response.into_body().into_reader(), &mut file).map_err(|e| UdpipeError {
+        message: format!("Failed to read response: {}", e),
+    })?;
+    drop(file);
+
+    if std::fs::metadata(&partial_path)?.len() == 0 {
         return Err(UdpipeError {
             message: "Downloaded file is empty".to_string(),
         });
     }
 
-    // Write to file
-    std::fs::write(path, &data)?;
-
+    std::fs::rename(&partial_path, dest_path)?;
     Ok(())
 }
 
-/// Returns the expected filename for a given language model.
+/// Downloads a pre-trained model by language identifier, reusing a
+/// checksum-verified copy from [`cache_dir`] when one already exists.
+///
+/// Unlike [`download_model`], this never re-fetches a model that's already
+/// been downloaded and verified, which matters for multi-hundred-MB models
+/// across repeated test/process runs. A partially downloaded file is
+/// resumed rather than restarted.
+///
+/// The checksum this function verifies against is recorded from the first
+/// successful download, not a known-good reference — this is trust on first
+/// use, not an integrity guarantee. If that initial download is truncated
+/// or corrupted in a way that still completes (e.g. a proxy serving a
+/// truncated response as 200 OK), the corrupt bytes are cached as
+/// "verified" and every later call will happily reuse them instead of
+/// re-fetching.
 ///
 /// # Example
 ///
+/// ```no_run
+/// use udpipe_rs::{download_model_cached, Model};
+///
+/// let model_path = download_model_cached("english-ewt").expect("Failed to download");
+/// let model = Model::load(&model_path).expect("Failed to load");
 /// ```
-/// assert_eq!(udpipe_rs::model_filename("english-ewt"), "english-ewt-ud-2.5-191206.udpipe");
-/// ```
-pub fn model_filename(language: &str) -> String {
-    format!("{}-ud-2.5-191206.udpipe", language)
+pub fn download_model_cached(language: &str) -> Result<String, UdpipeError> {
+    if !AVAILABLE_MODELS.contains(&language) {
+        return Err(UdpipeError {
+            message: format!(
+                "Unknown language '{}'. Use one of: {}",
+                language,
+                AVAILABLE_MODELS[..5].join(", ") + ", ..."
+            ),
+        });
+    }
+
+    let filename = model_filename(language);
+    let model_path = cache_dir()?.join(&filename);
+
+    if is_cached_and_verified(&model_path) {
+        return Ok(model_path.to_string_lossy().into_owned());
+    }
+
+    let url = format!("{}/{}", MODEL_BASE_URL, filename);
+    download_with_resume(&url, &model_path)?;
+
+    let data = std::fs::read(&model_path)?;
+    std::fs::write(
+        sibling_with_suffix(&model_path, ".sha256"),
+        sha256_hex(&data),
+    )?;
+
+    // Record the fresh download's fingerprint so the very next
+    // `is_cached_and_verified` call can take its fast path instead of
+    // re-hashing the file it just wrote.
+    if let Ok(metadata) = std::fs::metadata(&model_path) {
+        if let Some(fingerprint) = file_fingerprint(&metadata) {
+            let _ = std::fs::write(sibling_with_suffix(&model_path, ".meta"), fingerprint);
+        }
+    }
+
+    Ok(model_path.to_string_lossy().into_owned())
 }
 
 #[cfg(test)]
@@ -581,6 +2243,7 @@ mod tests {
             xpostag: String::new(),
             feats: feats.to_string(),
             deprel: "root".to_string(),
+            deps: String::new(),
             misc: String::new(),
             id: 1,
             head: 0,
@@ -712,6 +2375,90 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sibling_with_suffix() {
+        let path = Path::new("/tmp/english-ewt.udpipe");
+        assert_eq!(
+            sibling_with_suffix(path, ".sha256"),
+            Path::new("/tmp/english-ewt.udpipe.sha256")
+        );
+        assert_eq!(
+            sibling_with_suffix(path, ".partial"),
+            Path::new("/tmp/english-ewt.udpipe.partial")
+        );
+    }
+
+    #[test]
+    fn test_sha256_hex_known_vector() {
+        // sha256("abc") per the published NIST test vector.
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_is_cached_and_verified_missing_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let model_path = dir.path().join("missing.udpipe");
+        assert!(!is_cached_and_verified(&model_path));
+    }
+
+    #[test]
+    fn test_is_cached_and_verified_checksum_mismatch() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let model_path = dir.path().join("model.udpipe");
+        std::fs::write(&model_path, b"model bytes").expect("write model");
+        std::fs::write(sibling_with_suffix(&model_path, ".sha256"), "deadbeef")
+            .expect("write checksum");
+
+        assert!(!is_cached_and_verified(&model_path));
+    }
+
+    #[test]
+    fn test_is_cached_and_verified_matching_checksum() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let model_path = dir.path().join("model.udpipe");
+        std::fs::write(&model_path, b"model bytes").expect("write model");
+        std::fs::write(
+            sibling_with_suffix(&model_path, ".sha256"),
+            sha256_hex(b"model bytes"),
+        )
+        .expect("write checksum");
+
+        assert!(is_cached_and_verified(&model_path));
+    }
+
+    #[test]
+    fn test_is_cached_and_verified_skips_rehash_via_meta_fast_path() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let model_path = dir.path().join("model.udpipe");
+        std::fs::write(&model_path, b"model bytes").expect("write model");
+        std::fs::write(
+            sibling_with_suffix(&model_path, ".sha256"),
+            sha256_hex(b"model bytes"),
+        )
+        .expect("write checksum");
+
+        // First call does a full hash (since there's no `.meta` sidecar yet)
+        // and should record one for next time.
+        assert!(is_cached_and_verified(&model_path));
+
+        // Corrupt the recorded checksum; if the fast path weren't used, the
+        // next call would fall back to a full hash and fail here.
+        std::fs::write(sibling_with_suffix(&model_path, ".sha256"), "deadbeef")
+            .expect("corrupt checksum");
+        assert!(is_cached_and_verified(&model_path));
+    }
+
+    #[test]
+    fn test_download_model_cached_invalid_language() {
+        let result = download_model_cached("invalid-language-xyz");
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(err.message.contains("Unknown language"));
+    }
+
     #[test]
     fn test_available_models_contains_common_languages() {
         assert!(AVAILABLE_MODELS.contains(&"english-ewt"));
@@ -749,6 +2496,394 @@ mod tests {
         assert!(err.message.contains("not found"));
     }
 
+    #[test]
+    fn test_word_to_conllu() {
+        let word = make_word("Number=Sing");
+        assert_eq!(
+            word.to_conllu(),
+            "1\ttest\ttest\tNOUN\t_\tNumber=Sing\t0\troot\t_\t_"
+        );
+    }
+
+    #[test]
+    fn test_word_to_conllu_empty_fields() {
+        let word = make_word("");
+        assert_eq!(word.to_conllu(), "1\ttest\ttest\tNOUN\t_\t_\t0\troot\t_\t_");
+    }
+
+    #[test]
+    fn test_sentence_group_single_sentence() {
+        let words = vec![make_word(""), make_word("")];
+        let sentences = Sentence::group(words);
+        assert_eq!(sentences.len(), 1);
+        assert_eq!(sentences[0].words.len(), 2);
+    }
+
+    #[test]
+    fn test_sentence_group_multiple_sentences() {
+        let mut second = make_word("");
+        second.sentence_id = 1;
+        let sentences = Sentence::group(vec![make_word(""), second]);
+
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(sentences[0].words.len(), 1);
+        assert_eq!(sentences[1].words.len(), 1);
+    }
+
+    #[test]
+    fn test_sentence_group_empty() {
+        assert!(Sentence::group(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_sentence_text_respects_space_after() {
+        let mut comma = make_word("");
+        comma.form = ",".to_string();
+        comma.misc = "SpaceAfter=No".to_string();
+        let mut world = make_word("");
+        world.form = "world".to_string();
+
+        let sentence = Sentence {
+            words: vec![make_word(""), comma, world],
+            raw_rows: Vec::new(),
+        };
+        assert_eq!(sentence.text(), "test, world");
+    }
+
+    #[test]
+    fn test_sentence_to_conllu() {
+        let sentence = Sentence {
+            words: vec![make_word("")],
+            raw_rows: Vec::new(),
+        };
+        assert_eq!(
+            sentence.to_conllu(),
+            "# sent_id = 1\n# text = test\n1\ttest\ttest\tNOUN\t_\t_\t0\troot\t_\t_\n\n"
+        );
+    }
+
+    #[test]
+    fn test_sentence_from_conllu_round_trips() {
+        let sentence = Sentence {
+            words: vec![make_word("Number=Sing")],
+            raw_rows: Vec::new(),
+        };
+        let parsed = Sentence::from_conllu(&sentence.to_conllu()).expect("should parse");
+        assert_eq!(parsed, vec![sentence]);
+    }
+
+    #[test]
+    fn test_sentence_from_conllu_multiple_sentences() {
+        let conllu = "# text = Hi\n1\tHi\thi\tINTJ\t_\t_\t0\troot\t_\t_\n\n\
+                      # text = Bye\n1\tBye\tbye\tINTJ\t_\t_\t0\troot\t_\t_\n";
+        let sentences = Sentence::from_conllu(conllu).expect("should parse");
+
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(sentences[0].words[0].form, "Hi");
+        assert_eq!(sentences[1].words[0].form, "Bye");
+        assert_eq!(sentences[1].words[0].sentence_id, 1);
+    }
+
+    #[test]
+    fn test_sentence_from_conllu_preserves_multiword_and_empty_nodes() {
+        let conllu = "# text = can't\n\
+                      1-2\tcan't\t_\t_\t_\t_\t_\t_\t_\t_\n\
+                      1\tca\tcan\tAUX\t_\t_\t0\troot\t_\t_\n\
+                      2\tn't\tnot\tPART\t_\t_\t1\tadvmod\t_\t_\n\
+                      2.1\textra\textra\tVERB\t_\t_\t_\t_\t0:root\t_\n";
+        let sentences = Sentence::from_conllu(conllu).expect("should parse");
+
+        assert_eq!(sentences.len(), 1);
+        assert_eq!(sentences[0].words.len(), 2);
+        assert_eq!(sentences[0].words[0].id, 1);
+        assert_eq!(sentences[0].words[1].id, 2);
+        assert_eq!(
+            sentences[0].raw_rows,
+            vec![
+                (0, "1-2\tcan't\t_\t_\t_\t_\t_\t_\t_\t_".to_string()),
+                (
+                    2,
+                    "2.1\textra\textra\tVERB\t_\t_\t_\t_\t0:root\t_".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sentence_from_conllu_round_trips_multiword_and_empty_nodes() {
+        let conllu = "# text = can't\n\
+                      1-2\tcan't\t_\t_\t_\t_\t_\t_\t_\t_\n\
+                      1\tca\tcan\tAUX\t_\t_\t0\troot\t_\t_\n\
+                      2\tn't\tnot\tPART\t_\t_\t1\tadvmod\t_\t_\n\
+                      2.1\textra\textra\tVERB\t_\t_\t_\t_\t0:root\t_\n\n";
+        let sentences = Sentence::from_conllu(conllu).expect("should parse");
+        let round_tripped =
+            Sentence::from_conllu(&sentences[0].to_conllu()).expect("should re-parse");
+        assert_eq!(round_tripped, sentences);
+    }
+
+    #[test]
+    fn test_sentence_from_conllu_preserves_deps_column() {
+        let conllu = "1\tca\tcan\tAUX\t_\t_\t0\troot\t0:root\t_\n";
+        let sentences = Sentence::from_conllu(conllu).expect("should parse");
+        assert_eq!(sentences[0].words[0].deps, "0:root");
+        assert_eq!(
+            sentences[0].to_conllu(),
+            "# sent_id = 1\n# text = ca\n1\tca\tcan\tAUX\t_\t_\t0\troot\t0:root\t_\n\n"
+        );
+    }
+
+    #[test]
+    fn test_sentence_from_conllu_underscore_head_defaults_to_zero() {
+        let conllu = "1\ttest\ttest\tNOUN\t_\t_\t_\t_\t_\t_\n";
+        let sentences = Sentence::from_conllu(conllu).expect("should parse");
+        assert_eq!(sentences[0].words[0].head, 0);
+    }
+
+    #[test]
+    fn test_sentence_from_conllu_rejects_malformed_row() {
+        let err = Sentence::from_conllu("1\ttest\n").unwrap_err();
+        assert!(err.message.contains("10 tab-separated"));
+    }
+
+    #[test]
+    fn test_sentence_from_conllu_empty_input() {
+        assert!(Sentence::from_conllu("").expect("should parse").is_empty());
+    }
+
+    #[test]
+    fn test_sort_feats_orders_by_key() {
+        assert_eq!(sort_feats("VerbForm=Fin|Mood=Imp"), "Mood=Imp|VerbForm=Fin");
+        assert_eq!(sort_feats(""), "_");
+    }
+
+    #[test]
+    fn test_input_format_default_is_tokenizer() {
+        assert_eq!(InputFormat::default(), InputFormat::Tokenizer);
+    }
+
+    #[test]
+    fn test_input_format_as_str() {
+        assert_eq!(InputFormat::Tokenizer.as_str(), "tokenize");
+        assert_eq!(InputFormat::Horizontal.as_str(), "horizontal");
+        assert_eq!(InputFormat::Vertical.as_str(), "vertical");
+        assert_eq!(InputFormat::Conllu.as_str(), "conllu");
+    }
+
+    #[test]
+    fn test_parse_options_default_uses_tokenizer() {
+        assert_eq!(ParseOptions::default().input_format, InputFormat::Tokenizer);
+    }
+
+    #[test]
+    fn test_token_space_after() {
+        let mut token = Token {
+            form: "test".to_string(),
+            misc: String::new(),
+            id: 1,
+            sentence_id: 0,
+        };
+        assert!(token.space_after());
+
+        token.misc = "SpaceAfter=No".to_string();
+        assert!(!token.space_after());
+    }
+
+    #[test]
+    fn test_token_into_word() {
+        let token = Token {
+            form: "test".to_string(),
+            misc: "SpaceAfter=No".to_string(),
+            id: 2,
+            sentence_id: 1,
+        };
+        let word = token.into_word();
+
+        assert_eq!(word.form, "test");
+        assert_eq!(word.misc, "SpaceAfter=No");
+        assert_eq!(word.id, 2);
+        assert_eq!(word.sentence_id, 1);
+        assert!(word.lemma.is_empty());
+        assert!(word.upostag.is_empty());
+        assert_eq!(word.head, 0);
+    }
+
+    #[test]
+    fn test_token_from_word() {
+        let word = make_word("Mood=Imp");
+        let token = Token::from(word.clone());
+
+        assert_eq!(token.form, word.form);
+        assert_eq!(token.misc, word.misc);
+        assert_eq!(token.id, word.id);
+        assert_eq!(token.sentence_id, word.sentence_id);
+    }
+
+    #[test]
+    fn test_words_to_conllu_groups_sentences() {
+        let mut second = make_word("");
+        second.sentence_id = 1;
+        let conllu = words_to_conllu(&[make_word(""), second])
+            .unwrap()
+            .into_string()
+            .unwrap();
+
+        assert_eq!(conllu.matches("# text").count(), 2);
+    }
+
+    #[test]
+    fn test_apply_tagging_fills_in_tags() {
+        let mut words = vec![make_word("")];
+        words[0].lemma = String::new();
+        let mut tagged = make_word("Number=Sing");
+        tagged.lemma = "tagged".to_string();
+
+        apply_tagging(&mut words, &[tagged]).expect("lengths match");
+        assert_eq!(words[0].lemma, "tagged");
+        assert_eq!(words[0].feats, "Number=Sing");
+    }
+
+    #[test]
+    fn test_apply_tagging_length_mismatch() {
+        let mut words = vec![make_word("")];
+        let err = apply_tagging(&mut words, &[]).unwrap_err();
+        assert!(err.message.contains("expected 1"));
+    }
+
+    #[test]
+    fn test_apply_dependencies_fills_in_head_and_deprel() {
+        let mut words = vec![make_word("")];
+        let mut parsed = make_word("");
+        parsed.head = 3;
+        parsed.deprel = "obj".to_string();
+
+        apply_dependencies(&mut words, &[parsed]).expect("lengths match");
+        assert_eq!(words[0].head, 3);
+        assert_eq!(words[0].deprel, "obj");
+    }
+
+    #[test]
+    fn test_apply_dependencies_length_mismatch() {
+        let mut words = vec![make_word("")];
+        let err = apply_dependencies(&mut words, &[]).unwrap_err();
+        assert!(err.message.contains("expected 1"));
+    }
+
+    /// Builds a small well-formed tree for "The cat sleeps quickly.":
+    /// sleeps (root) -> cat (nsubj), quickly (advmod); cat -> The (det).
+    fn make_tree_words() -> Vec<Word> {
+        let word = |id, head, form: &str, deprel: &str| Word {
+            form: form.to_string(),
+            lemma: form.to_lowercase(),
+            upostag: String::new(),
+            xpostag: String::new(),
+            feats: String::new(),
+            deprel: deprel.to_string(),
+            deps: String::new(),
+            misc: String::new(),
+            id,
+            head,
+            sentence_id: 0,
+        };
+        vec![
+            word(1, 2, "The", "det"),
+            word(2, 3, "cat", "nsubj"),
+            word(3, 0, "sleeps", "root"),
+            word(4, 3, "quickly", "advmod"),
+        ]
+    }
+
+    #[test]
+    fn test_dependency_tree_root() {
+        let words = make_tree_words();
+        let tree = DependencyTree::new(&words).expect("well-formed tree");
+        assert_eq!(tree.root().form, "sleeps");
+    }
+
+    #[test]
+    fn test_dependency_tree_parent() {
+        let words = make_tree_words();
+        let tree = DependencyTree::new(&words).expect("well-formed tree");
+
+        assert_eq!(tree.parent(1).unwrap().form, "cat");
+        assert_eq!(tree.parent(2).unwrap().form, "sleeps");
+        assert!(tree.parent(3).is_none());
+    }
+
+    #[test]
+    fn test_dependency_tree_children() {
+        let words = make_tree_words();
+        let tree = DependencyTree::new(&words).expect("well-formed tree");
+
+        let children: Vec<_> = tree.children(3).map(|w| w.form.as_str()).collect();
+        assert_eq!(children, vec!["cat", "quickly"]);
+        assert!(tree.children(1).next().is_none());
+    }
+
+    #[test]
+    fn test_dependency_tree_subtree() {
+        let words = make_tree_words();
+        let tree = DependencyTree::new(&words).expect("well-formed tree");
+
+        let subtree: Vec<_> = tree.subtree(2).map(|w| w.form.as_str()).collect();
+        assert_eq!(subtree, vec!["The", "cat"]);
+
+        let whole: Vec<_> = tree.subtree(3).map(|w| w.form.as_str()).collect();
+        assert_eq!(whole, vec!["The", "cat", "sleeps", "quickly"]);
+    }
+
+    #[test]
+    fn test_dependency_tree_subtree_text() {
+        let words = make_tree_words();
+        let tree = DependencyTree::new(&words).expect("well-formed tree");
+        assert_eq!(tree.subtree_text(2), "The cat");
+    }
+
+    #[test]
+    fn test_dependency_tree_rejects_multiple_roots() {
+        let mut words = make_tree_words();
+        words[0].head = 0;
+        let err = DependencyTree::new(&words).unwrap_err();
+        assert!(err.message.contains("found 2"));
+    }
+
+    #[test]
+    fn test_dependency_tree_rejects_no_root() {
+        let mut words = make_tree_words();
+        words[2].head = 4;
+        let err = DependencyTree::new(&words).unwrap_err();
+        assert!(err.message.contains("found 0"));
+    }
+
+    #[test]
+    fn test_dependency_tree_rejects_dangling_head() {
+        let mut words = make_tree_words();
+        words[0].head = 99;
+        let err = DependencyTree::new(&words).unwrap_err();
+        assert!(err.message.contains("dangling head"));
+    }
+
+    #[test]
+    fn test_dependency_tree_rejects_cycle() {
+        let mut words = make_tree_words();
+        // Make `cat` (id 2) and `quickly` (id 4) point at each other, but
+        // keep a single head == 0 root so only the cycle check can catch it.
+        words[1].head = 4;
+        words[3].head = 2;
+        let err = DependencyTree::new(&words).unwrap_err();
+        assert!(err.message.contains("Cycle detected"));
+    }
+
+    #[test]
+    fn test_sentence_dependency_tree() {
+        let sentence = Sentence {
+            words: make_tree_words(),
+            raw_rows: Vec::new(),
+        };
+        let tree = sentence.dependency_tree().expect("well-formed tree");
+        assert_eq!(tree.root().form, "sleeps");
+    }
+
     #[test]
     fn test_space_after() {
         let mut word = make_word("");
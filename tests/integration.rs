@@ -4,6 +4,8 @@
 
 use std::sync::OnceLock;
 
+use udpipe_rs::ParseOptions;
+
 const MODEL_LANGUAGE: &str = "english-ewt";
 
 static MODEL: OnceLock<(tempfile::TempDir, udpipe_rs::Model)> = OnceLock::new();
@@ -29,7 +31,9 @@ fn get_model() -> &'static udpipe_rs::Model {
 #[test]
 fn test_parse_simple_sentence() {
     let model = get_model();
-    let words = model.parse("Hello world!").expect("Failed to parse");
+    let words = model
+        .parse("Hello world!", ParseOptions::default())
+        .expect("Failed to parse");
 
     assert!(!words.is_empty());
     assert!(words.iter().any(|w| w.form == "Hello"));
@@ -40,7 +44,7 @@ fn test_parse_simple_sentence() {
 fn test_parse_multiple_sentences() {
     let model = get_model();
     let words = model
-        .parse("The cat sat. The dog ran.")
+        .parse("The cat sat. The dog ran.", ParseOptions::default())
         .expect("Failed to parse");
 
     // Should have words from both sentences
@@ -56,7 +60,7 @@ fn test_parse_multiple_sentences() {
 fn test_word_ids_are_sequential() {
     let model = get_model();
     let words = model
-        .parse("The quick brown fox.")
+        .parse("The quick brown fox.", ParseOptions::default())
         .expect("Failed to parse");
 
     assert!(!words.is_empty(), "Should have parsed words");
@@ -70,7 +74,9 @@ fn test_word_ids_are_sequential() {
 #[test]
 fn test_dependency_structure() {
     let model = get_model();
-    let words = model.parse("The cat sleeps.").expect("Failed to parse");
+    let words = model
+        .parse("The cat sleeps.", ParseOptions::default())
+        .expect("Failed to parse");
 
     // Should have exactly one root
     let roots: Vec<_> = words.iter().filter(|w| w.is_root()).collect();
@@ -87,7 +93,9 @@ fn test_dependency_structure() {
 #[test]
 fn test_morphological_features() {
     let model = get_model();
-    let words = model.parse("She runs quickly.").expect("Failed to parse");
+    let words = model
+        .parse("She runs quickly.", ParseOptions::default())
+        .expect("Failed to parse");
 
     // Find the verb "runs"
     let verb = words.iter().find(|w| w.lemma == "run");
@@ -105,7 +113,9 @@ fn test_morphological_features() {
 #[test]
 fn test_empty_input() {
     let model = get_model();
-    let words = model.parse("").expect("Should handle empty input");
+    let words = model
+        .parse("", ParseOptions::default())
+        .expect("Should handle empty input");
 
     assert!(words.is_empty(), "Empty input should produce no words");
 }
@@ -116,7 +126,7 @@ fn test_unicode_input() {
 
     // Test with various Unicode characters
     let words = model
-        .parse("Héllo wörld! 你好")
+        .parse("Héllo wörld! 你好", ParseOptions::default())
         .expect("Should handle Unicode");
     assert!(!words.is_empty());
 }
@@ -124,7 +134,9 @@ fn test_unicode_input() {
 #[test]
 fn test_misc_field_space_after() {
     let model = get_model();
-    let words = model.parse("Hello, world!").expect("Failed to parse");
+    let words = model
+        .parse("Hello, world!", ParseOptions::default())
+        .expect("Failed to parse");
 
     // Most words have space after, some (before punctuation) don't
     let has_space = words.iter().filter(|w| w.space_after()).count();
@@ -141,7 +153,9 @@ fn test_misc_field_space_after() {
 #[test]
 fn test_xpostag_field() {
     let model = get_model();
-    let words = model.parse("The cat sleeps.").expect("Failed to parse");
+    let words = model
+        .parse("The cat sleeps.", ParseOptions::default())
+        .expect("Failed to parse");
 
     assert!(!words.is_empty(), "Should have parsed words");
 
@@ -155,7 +169,7 @@ fn test_xpostag_field() {
 #[test]
 fn test_parse_with_null_byte() {
     let model = get_model();
-    let result = model.parse("Hello\0world");
+    let result = model.parse("Hello\0world", ParseOptions::default());
     assert!(result.is_err());
     let err = result.unwrap_err();
     assert!(err.message.contains("null byte"));
@@ -176,7 +190,9 @@ fn test_load_from_memory() {
         udpipe_rs::Model::load_from_memory(&model_data).expect("Failed to load from memory");
 
     // Verify it works
-    let words = model.parse("Test sentence.").expect("Failed to parse");
+    let words = model
+        .parse("Test sentence.", ParseOptions::default())
+        .expect("Failed to parse");
     assert!(!words.is_empty());
 }
 
@@ -190,3 +206,224 @@ fn test_model_drop() {
     let model = udpipe_rs::Model::load(&model_path).expect("Failed to load model");
     drop(model); // Explicit drop - coverage tools sometimes miss implicit drops
 }
+
+#[test]
+fn test_parse_batch_preserves_order() {
+    let model = get_model();
+    let texts = ["The cat sat.", "A dog ran.", "Birds fly."];
+
+    let results = model.parse_batch(&texts);
+    assert_eq!(results.len(), texts.len());
+
+    let first_forms: Vec<_> = results[0]
+        .as_ref()
+        .expect("Failed to parse")
+        .iter()
+        .map(|w| w.form.as_str())
+        .collect();
+    assert!(first_forms.contains(&"cat"));
+
+    let second_forms: Vec<_> = results[1]
+        .as_ref()
+        .expect("Failed to parse")
+        .iter()
+        .map(|w| w.form.as_str())
+        .collect();
+    assert!(second_forms.contains(&"dog"));
+}
+
+#[test]
+fn test_parse_batch_isolates_errors() {
+    let model = get_model();
+    let texts = ["Good input.", "Bad\0input.", "Another good one."];
+
+    let results = model.parse_batch(&texts);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+}
+
+#[test]
+fn test_parse_batch_empty() {
+    let model = get_model();
+    assert!(model.parse_batch(&[]).is_empty());
+}
+
+#[test]
+fn test_parallel_parser_preserves_sentence_order() {
+    use std::sync::Arc;
+    use udpipe_rs::ParallelParser;
+
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let model_path = udpipe_rs::download_model(MODEL_LANGUAGE, temp_dir.path())
+        .expect("Failed to download model");
+    let model = Arc::new(udpipe_rs::Model::load(&model_path).expect("Failed to load model"));
+    let parser = ParallelParser::new(model);
+
+    let sentences = parser
+        .par_parse("The cat sat. The dog ran. Birds fly.")
+        .expect("Failed to parse");
+
+    assert_eq!(sentences.len(), 3);
+    assert!(sentences[0].words.iter().any(|w| w.form == "cat"));
+    assert!(sentences[1].words.iter().any(|w| w.form == "dog"));
+    assert!(sentences[2].words.iter().any(|w| w.form == "Birds"));
+}
+
+#[test]
+fn test_parser_returns_sentences() {
+    let model = get_model();
+    let sentences: Vec<_> = model
+        .parser("The cat sat. The dog ran.", ParseOptions::default())
+        .expect("Failed to create parser")
+        .collect::<Result<_, _>>()
+        .expect("Failed to parse");
+
+    assert_eq!(sentences.len(), 2);
+    assert!(sentences[0].words.iter().any(|w| w.form == "cat"));
+    assert!(sentences[1].words.iter().any(|w| w.form == "dog"));
+}
+
+#[test]
+fn test_parse_with_options_horizontal() {
+    use udpipe_rs::InputFormat;
+
+    let model = get_model();
+    let sentences = model
+        .parse_with_options(
+            "The quick brown fox .",
+            ParseOptions {
+                input_format: InputFormat::Horizontal,
+            },
+        )
+        .expect("Failed to parse");
+
+    assert_eq!(sentences.len(), 1);
+    assert!(sentences[0].words.iter().any(|w| w.form == "fox"));
+}
+
+#[test]
+fn test_parse_with_options_vertical() {
+    use udpipe_rs::InputFormat;
+
+    let model = get_model();
+    let sentences = model
+        .parse_with_options(
+            "The\nquick\nbrown\nfox\n.\n",
+            ParseOptions {
+                input_format: InputFormat::Vertical,
+            },
+        )
+        .expect("Failed to parse");
+
+    assert_eq!(sentences.len(), 1);
+    assert!(sentences[0].words.iter().any(|w| w.form == "fox"));
+}
+
+#[test]
+fn test_parse_with_options_conllu() {
+    use udpipe_rs::InputFormat;
+
+    let model = get_model();
+    let conllu = "# text = Hi\n1\tHi\t_\t_\t_\t_\t_\t_\t_\t_\n\n";
+    let sentences = model
+        .parse_with_options(
+            conllu,
+            ParseOptions {
+                input_format: InputFormat::Conllu,
+            },
+        )
+        .expect("Failed to parse");
+
+    assert_eq!(sentences.len(), 1);
+    assert!(sentences[0].words.iter().any(|w| w.form == "Hi"));
+}
+
+#[test]
+fn test_parser_from_reader_yields_sentences() {
+    use std::io::Cursor;
+
+    let model = get_model();
+    let reader = Cursor::new("The cat sat. The dog ran. Birds fly.");
+    let sentences: Vec<_> = model
+        .parser_from_reader(reader)
+        .collect::<Result<_, _>>()
+        .expect("Failed to parse");
+
+    assert_eq!(sentences.len(), 3);
+    assert!(sentences[0].words.iter().any(|w| w.form == "cat"));
+    assert!(sentences[2].words.iter().any(|w| w.form == "Birds"));
+}
+
+#[test]
+fn test_parser_from_reader_handles_small_buffer_and_boundaries() {
+    use std::io::Cursor;
+
+    let model = get_model();
+    // A tiny buffer forces many reads, repeatedly splitting sentences (and
+    // possibly characters) across chunk boundaries.
+    let reader = Cursor::new("The cat sat. The dog ran. Birds fly.");
+    let sentences: Vec<_> = model
+        .parser_from_reader_with_capacity(reader, 5)
+        .collect::<Result<_, _>>()
+        .expect("Failed to parse");
+
+    assert_eq!(sentences.len(), 3);
+    assert!(sentences[1].words.iter().any(|w| w.form == "dog"));
+}
+
+#[test]
+fn test_parser_from_reader_splits_on_space_after_earlier_sentence() {
+    use std::io::Cursor;
+
+    let model = get_model();
+    // With a 6-byte buffer, "X. aa bb." fills its first chunk as "X. aa "
+    // (6 bytes), landing the split exactly on the inter-word space of the
+    // still-incomplete second sentence rather than mid-word. A lossy tail
+    // (e.g. one reconstructed via `Sentence::text()`) drops that space and
+    // glues "aa" and "bb" into one token.
+    let reader = Cursor::new("X. aa bb.");
+    let sentences: Vec<_> = model
+        .parser_from_reader_with_capacity(reader, 6)
+        .collect::<Result<_, _>>()
+        .expect("Failed to parse");
+
+    assert_eq!(sentences.len(), 2);
+    assert!(sentences[1].words.iter().any(|w| w.form == "aa"));
+    assert!(sentences[1].words.iter().any(|w| w.form == "bb"));
+    assert!(
+        !sentences[1].words.iter().any(|w| w.form == "aabb"),
+        "boundary space must be preserved, not dropped"
+    );
+}
+
+#[test]
+fn test_parser_from_reader_empty_input() {
+    use std::io::Cursor;
+
+    let model = get_model();
+    let reader = Cursor::new("");
+    let sentences: Vec<_> = model
+        .parser_from_reader(reader)
+        .collect::<Result<_, _>>()
+        .expect("Failed to parse");
+
+    assert!(sentences.is_empty());
+}
+
+#[test]
+fn test_parallel_parser_empty_input() {
+    use std::sync::Arc;
+    use udpipe_rs::ParallelParser;
+
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+    let model_path = udpipe_rs::download_model(MODEL_LANGUAGE, temp_dir.path())
+        .expect("Failed to download model");
+    let model = Arc::new(udpipe_rs::Model::load(&model_path).expect("Failed to load model"));
+    let parser = ParallelParser::new(model);
+
+    assert!(parser
+        .par_parse("")
+        .expect("Should handle empty input")
+        .is_empty());
+}